@@ -2,23 +2,11 @@ use std::time::{Duration, Instant};
 
 use benches::bench;
 use criterion::{Criterion, criterion_group};
-use rand::Rng;
+use graph_constructors::random::gnp;
 use squareknot_graph::{AbstractGraph, SimpleGraph};
 use squareknot_traversal::TraversalView;
 
-criterion_group!(benches, traverse_component_1000);
-
-fn gen_graph(nv: usize, p: f32) -> SimpleGraph {
-    let mut g = SimpleGraph::empty(nv);
-    for u in 0..nv {
-        for v in 0..u {
-            if rand::rng().random::<f32>() < p {
-                g.add_edge(u, v);
-            }
-        }
-    }
-    g
-}
+criterion_group!(benches, traverse_component_1000, parallel_traverse_component_1000);
 
 fn traverse_component_1000(c: &mut Criterion) {
     let d = [0.1, 0.2, 0.4, 0.8];
@@ -28,7 +16,7 @@ fn traverse_component_1000(c: &mut Criterion) {
             b.iter_custom(|iters| {
                 let mut total = Duration::ZERO;
                 for _i in 0..iters {
-                    let g = gen_graph(1000, p);
+                    let g: SimpleGraph = gnp(1000, p, &mut rand::rng());
                     let start = Instant::now();
                     g.view().bfs(0).count();
                     total += start.elapsed();
@@ -38,3 +26,22 @@ fn traverse_component_1000(c: &mut Criterion) {
         });
     }
 }
+
+fn parallel_traverse_component_1000(c: &mut Criterion) {
+    let d = [0.1, 0.2, 0.4, 0.8];
+    for p in d {
+        let s = format!("parallel_traverse_component_1000_{p}p");
+        c.bench_function(&bench!(s), |b| {
+            b.iter_custom(|iters| {
+                let mut total = Duration::ZERO;
+                for _i in 0..iters {
+                    let g: SimpleGraph = gnp(1000, p, &mut rand::rng());
+                    let start = Instant::now();
+                    g.view().parallel_bfs(0);
+                    total += start.elapsed();
+                }
+                total
+            });
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use squareknot_graph::FastGraph;
+
+use crate::EdgeBuilder;
+
+/// The complete graph `K_n`.
+pub fn complete<G: FastGraph>(n: usize) -> G {
+    let mut builder = EdgeBuilder::new(n);
+    builder.clique(0..n);
+    builder.build()
+}
+
+/// The complete bipartite graph `K_{m,n}`.
+/// The first part is `0..m`, and the second part is `m..m + n`.
+pub fn complete_bipartite<G: FastGraph>(m: usize, n: usize) -> G {
+    complete_multipartite(&[m, n])
+}
+
+/// The complete multipartite graph with the given part sizes.
+/// Parts are labeled consecutively, in the order given.
+pub fn complete_multipartite<G: FastGraph>(parts: &[usize]) -> G {
+    let n = parts.iter().sum();
+    let mut builder = EdgeBuilder::new(n);
+    let mut start = 0;
+    for &size in parts {
+        for v in start..start + size {
+            for u in start + size..n {
+                builder.edge(v, u);
+            }
+        }
+        start += size;
+    }
+    builder.build()
+}
+
+/// The path `P_n` on `n` vertices, visiting them in order.
+pub fn path<G: FastGraph>(n: usize) -> G {
+    let mut builder = EdgeBuilder::new(n);
+    builder.path(0..n);
+    builder.build()
+}
+
+/// The cycle `C_n`, visiting the vertices in order.
+///
+/// # Panics
+///
+/// Panics if `n < 3`.
+pub fn cycle<G: FastGraph>(n: usize) -> G {
+    assert!(n >= 3, "a cycle requires at least 3 vertices");
+    let mut builder = EdgeBuilder::new(n);
+    builder.path(0..n);
+    builder.edge(n - 1, 0);
+    builder.build()
+}
+
+/// The star `K_{1,n}`, with center `0` and leaves `1..=n`.
+pub fn star<G: FastGraph>(n: usize) -> G {
+    let mut builder = EdgeBuilder::new(n + 1);
+    for v in 1..=n {
+        builder.edge(0, v);
+    }
+    builder.build()
+}
+
+/// The wheel with hub `0` and a rim cycle on `1..=n`.
+///
+/// # Panics
+///
+/// Panics if `n < 3`.
+pub fn wheel<G: FastGraph>(n: usize) -> G {
+    assert!(n >= 3, "the rim of a wheel requires at least 3 vertices");
+    let mut builder = EdgeBuilder::new(n + 1);
+    for v in 1..=n {
+        builder.edge(0, v);
+    }
+    builder.path(1..n + 1);
+    builder.edge(n, 1);
+    builder.build()
+}
+
+/// The friendship graph `F_n`: `n` triangles sharing the vertex `0`.
+/// Triangle `i` consists of `0`, `2i + 1` and `2i + 2`.
+pub fn friendship<G: FastGraph>(n: usize) -> G {
+    let mut builder = EdgeBuilder::new(2 * n + 1);
+    for i in 0..n {
+        builder.edge(0, 2 * i + 1);
+        builder.edge(0, 2 * i + 2);
+        builder.edge(2 * i + 1, 2 * i + 2);
+    }
+    builder.build()
+}
+
+/// The barbell graph: two copies of `K_m` joined by a path through `l` further vertices.
+///
+/// The cliques are `0..m` and `m + l..2m + l`, and the path runs from `m - 1` through `m..m + l` to `m + l`.
+///
+/// # Panics
+///
+/// Panics if `m < 1`.
+pub fn barbell<G: FastGraph>(m: usize, l: usize) -> G {
+    assert!(m >= 1, "the cliques of a barbell require at least 1 vertex");
+    let mut builder = EdgeBuilder::new(2 * m + l);
+    builder.clique(0..m);
+    builder.clique(m + l..2 * m + l);
+    builder.path(m - 1..m + l + 1);
+    builder.build()
+}
+
+/// The lollipop graph: a copy of `K_m` joined to a path on `l` further vertices.
+///
+/// The clique is `0..m`, and the path runs from `m - 1` through `m..m + l`.
+///
+/// # Panics
+///
+/// Panics if `m < 1`.
+pub fn lollipop<G: FastGraph>(m: usize, l: usize) -> G {
+    assert!(
+        m >= 1,
+        "the clique of a lollipop requires at least 1 vertex"
+    );
+    let mut builder = EdgeBuilder::new(m + l);
+    builder.clique(0..m);
+    builder.path(m - 1..m + l);
+    builder.build()
+}
@@ -0,0 +1,57 @@
+use squareknot_graph::FastGraph;
+
+use crate::EdgeBuilder;
+
+/// The `rows` by `cols` grid graph. The vertex in row `r` and column `c` is `r * cols + c`.
+pub fn grid<G: FastGraph>(rows: usize, cols: usize) -> G {
+    let mut builder = EdgeBuilder::new(rows * cols);
+    for r in 0..rows {
+        for c in 0..cols {
+            let v = r * cols + c;
+            if c + 1 < cols {
+                builder.edge(v, v + 1);
+            }
+            if r + 1 < rows {
+                builder.edge(v, v + cols);
+            }
+        }
+    }
+    builder.build()
+}
+
+/// The `rows` by `cols` torus, i.e. the grid graph with opposite sides joined.
+/// The vertex in row `r` and column `c` is `r * cols + c`.
+///
+/// # Panics
+///
+/// Panics if `rows < 3` or `cols < 3`, as the torus would not be simple.
+pub fn torus<G: FastGraph>(rows: usize, cols: usize) -> G {
+    assert!(
+        rows >= 3 && cols >= 3,
+        "a torus requires at least 3 rows and 3 columns"
+    );
+    let mut builder = EdgeBuilder::new(rows * cols);
+    for r in 0..rows {
+        for c in 0..cols {
+            let v = r * cols + c;
+            builder.edge(v, r * cols + (c + 1) % cols);
+            builder.edge(v, (r + 1) % rows * cols + c);
+        }
+    }
+    builder.build()
+}
+
+/// The hypercube `Q_d` on `2^d` vertices, in which vertices are adjacent when their labels differ in one bit.
+pub fn hypercube<G: FastGraph>(d: u32) -> G {
+    let n = 1 << d;
+    let mut builder = EdgeBuilder::new(n);
+    for v in 0..n {
+        for bit in 0..d {
+            let u = v ^ (1 << bit);
+            if u > v {
+                builder.edge(v, u);
+            }
+        }
+    }
+    builder.build()
+}
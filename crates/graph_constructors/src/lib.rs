@@ -1,14 +1,67 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
+//! Constructors for common families of graphs.
+//!
+//! Every constructor produces any [`FastGraph`]. Vertices are labeled `0..n`, in the order documented
+//! by each constructor. When the target graph is directed, each edge is added as a pair of opposite arcs.
+
+pub mod classic;
+pub use classic::*;
+
+pub mod lattice;
+pub use lattice::*;
+
+pub mod random;
+
+pub mod symmetric;
+pub use symmetric::*;
+
+use squareknot_graph::FastGraph;
+
+/// Builds a graph from edges which are known to be distinct.
+pub(crate) struct EdgeBuilder<G: FastGraph> {
+    graph: G,
+    directed: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl<G: FastGraph> EdgeBuilder<G> {
+    pub(crate) fn new(n: usize) -> Self {
+        // A graph type is treated as directed if adding an edge does not add its reverse
+        let mut probe = G::empty(2);
+        probe.add_edge(0, 1);
+        Self {
+            graph: G::empty(n),
+            directed: !probe.has_edge(1, 0),
+        }
+    }
+
+    /// Adds the edge `{u, v}`.
+    /// Each edge must be added at most once, in either orientation, and `u` must differ from `v`.
+    pub(crate) fn edge(&mut self, u: usize, v: usize) {
+        debug_assert!(u != v && !self.graph.has_edge(u, v));
+        unsafe {
+            self.graph.add_edge_unchecked(u, v);
+            if self.directed {
+                self.graph.add_edge_unchecked(v, u);
+            }
+        }
+    }
+
+    /// Adds every edge between distinct vertices of `vertices`.
+    pub(crate) fn clique(&mut self, vertices: std::ops::Range<usize>) {
+        for v in vertices.clone() {
+            for u in vertices.start..v {
+                self.edge(u, v);
+            }
+        }
+    }
+
+    /// Adds the edges of a path through `vertices`, in order.
+    pub(crate) fn path(&mut self, vertices: std::ops::Range<usize>) {
+        for v in vertices.clone().skip(1) {
+            self.edge(v - 1, v);
+        }
+    }
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    pub(crate) fn build(self) -> G {
+        self.graph
     }
 }
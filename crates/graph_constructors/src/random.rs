@@ -0,0 +1,375 @@
+//! Random graph models.
+//!
+//! Every generator draws from the supplied [`Rng`], so seeding it (e.g. with [`rand::SeedableRng::seed_from_u64`])
+//! makes the output reproducible.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use rand::{Rng, seq::SliceRandom};
+use squareknot_graph::FastGraph;
+
+use crate::EdgeBuilder;
+
+/// Returns the number of pairs to skip before the next pair is chosen, when each is chosen with probability `p`.
+fn skip<R: Rng + ?Sized>(log_q: f64, rng: &mut R) -> usize {
+    let r: f64 = rng.random();
+    ((1.0 - r).ln() / log_q).floor() as usize
+}
+
+/// Adds each edge within `vertices` independently with probability `p`.
+///
+/// This uses the geometric skipping method of Batagelj and Brandes, which runs in time proportional to the
+/// number of vertices and edges.
+fn sample_within<G: FastGraph, R: Rng + ?Sized>(
+    builder: &mut EdgeBuilder<G>,
+    vertices: std::ops::Range<usize>,
+    p: f64,
+    rng: &mut R,
+) {
+    if p <= 0.0 {
+        return;
+    }
+    if p >= 1.0 {
+        builder.clique(vertices);
+        return;
+    }
+    let log_q = (1.0 - p).ln();
+    let n = vertices.len();
+    // The next candidate is `(v, w)` with `w < v`
+    let (mut v, mut w): (usize, usize) = (1, 0);
+    while v < n {
+        w = w.saturating_add(skip(log_q, rng));
+        while w >= v && v < n {
+            w -= v;
+            v += 1;
+        }
+        if v < n {
+            builder.edge(vertices.start + w, vertices.start + v);
+            w += 1;
+        }
+    }
+}
+
+/// Adds each edge between the disjoint ranges `a` and `b` independently with probability `p`.
+fn sample_between<G: FastGraph, R: Rng + ?Sized>(
+    builder: &mut EdgeBuilder<G>,
+    a: std::ops::Range<usize>,
+    b: std::ops::Range<usize>,
+    p: f64,
+    rng: &mut R,
+) {
+    if p <= 0.0 || b.is_empty() {
+        return;
+    }
+    let total = a.len() * b.len();
+    let log_q = (1.0 - p).ln();
+    let mut index: usize = 0;
+    loop {
+        if p < 1.0 {
+            index = index.saturating_add(skip(log_q, rng));
+        }
+        if index >= total {
+            break;
+        }
+        builder.edge(a.start + index / b.len(), b.start + index % b.len());
+        index += 1;
+    }
+}
+
+/// The Erdős–Rényi graph `G(n, p)`, in which each edge is present independently with probability `p`.
+///
+/// Runs in expected time `O(n + m)`, so sparse graphs are cheap to generate.
+pub fn gnp<G: FastGraph, R: Rng + ?Sized>(n: usize, p: f64, rng: &mut R) -> G {
+    let mut builder = EdgeBuilder::new(n);
+    sample_within(&mut builder, 0..n, p, rng);
+    builder.build()
+}
+
+/// The Erdős–Rényi graph `G(n, m)`, chosen uniformly among graphs on `n` vertices with `m` edges.
+///
+/// # Panics
+///
+/// Panics if `m` exceeds `n(n - 1) / 2`.
+pub fn gnm<G: FastGraph, R: Rng + ?Sized>(n: usize, m: usize, rng: &mut R) -> G {
+    let max = n * n.saturating_sub(1) / 2;
+    assert!(m <= max, "G(n, m) requires m <= n(n - 1) / 2");
+
+    // Dense graphs are generated by choosing the missing edges instead
+    let complement = m > max / 2;
+    let count = if complement { max - m } else { m };
+    let mut chosen = HashSet::with_capacity(count);
+    let mut edges = Vec::with_capacity(count);
+    while edges.len() < count {
+        let u = rng.random_range(0..n);
+        let v = rng.random_range(0..n);
+        if u != v && chosen.insert((u.min(v), u.max(v))) {
+            edges.push((u, v));
+        }
+    }
+
+    let mut builder = EdgeBuilder::new(n);
+    if complement {
+        for v in 0..n {
+            for u in 0..v {
+                if !chosen.contains(&(u, v)) {
+                    builder.edge(u, v);
+                }
+            }
+        }
+    } else {
+        for (u, v) in edges {
+            builder.edge(u, v);
+        }
+    }
+    builder.build()
+}
+
+/// The Barabási–Albert preferential attachment graph.
+///
+/// Vertices `m..n` arrive in order, each joining `m` distinct earlier vertices chosen with probability
+/// proportional to their degree. The first arrival joins all of `0..m`.
+///
+/// # Panics
+///
+/// Panics unless `1 <= m < n`.
+pub fn barabasi_albert<G: FastGraph, R: Rng + ?Sized>(n: usize, m: usize, rng: &mut R) -> G {
+    assert!(
+        m >= 1 && m < n,
+        "preferential attachment requires 1 <= m < n"
+    );
+    let mut builder = EdgeBuilder::new(n);
+    // Each vertex appears once per incident edge
+    let mut endpoints = Vec::with_capacity(2 * m * (n - m));
+    let mut targets = (0..m).collect::<Vec<_>>();
+    for source in m..n {
+        for &target in &targets {
+            builder.edge(target, source);
+            endpoints.push(target);
+            endpoints.push(source);
+        }
+        targets.clear();
+        while targets.len() < m {
+            let target = endpoints[rng.random_range(0..endpoints.len())];
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+    }
+    builder.build()
+}
+
+/// The Watts–Strogatz small-world graph.
+///
+/// Starts from a ring on `0..n` in which each vertex is joined to its `k / 2` nearest neighbors on each side.
+/// Then each edge `(u, u + j)` is rewired with probability `beta` to `(u, w)` for a uniformly chosen `w`
+/// which is not already a neighbor of `u`.
+///
+/// # Panics
+///
+/// Panics unless `k` is even and `k < n`.
+pub fn watts_strogatz<G: FastGraph, R: Rng + ?Sized>(
+    n: usize,
+    k: usize,
+    beta: f64,
+    rng: &mut R,
+) -> G {
+    assert!(
+        k.is_multiple_of(2) && k < n,
+        "a small-world graph requires an even k < n"
+    );
+    let mut adjacency = vec![BTreeSet::new(); n];
+    for u in 0..n {
+        for j in 1..=k / 2 {
+            let v = (u + j) % n;
+            adjacency[u].insert(v);
+            adjacency[v].insert(u);
+        }
+    }
+    for j in 1..=k / 2 {
+        for u in 0..n {
+            let v = (u + j) % n;
+            if rng.random::<f64>() >= beta || adjacency[u].len() + 1 >= n {
+                continue;
+            }
+            let w = loop {
+                let w = rng.random_range(0..n);
+                if w != u && !adjacency[u].contains(&w) {
+                    break w;
+                }
+            };
+            adjacency[u].remove(&v);
+            adjacency[v].remove(&u);
+            adjacency[u].insert(w);
+            adjacency[w].insert(u);
+        }
+    }
+
+    let mut builder = EdgeBuilder::new(n);
+    for (u, neighbors) in adjacency.iter().enumerate() {
+        for &v in neighbors.range(u + 1..) {
+            builder.edge(u, v);
+        }
+    }
+    builder.build()
+}
+
+/// A random `d`-regular graph on `n` vertices.
+///
+/// Stubs are paired at random as in the configuration model, but a pair which would form a self-loop or a
+/// repeated edge is returned to the pool to be paired again, rather than rejecting the whole pairing.
+/// This is fast even for large `d`, at the cost of exact uniformity:
+/// the distribution is only asymptotically uniform, for `d` small relative to `n`.
+///
+/// # Panics
+///
+/// Panics unless `d < n` and `n * d` is even.
+pub fn random_regular<G: FastGraph, R: Rng + ?Sized>(n: usize, d: usize, rng: &mut R) -> G {
+    assert!(
+        d < n.max(1) && (n * d).is_multiple_of(2),
+        "a d-regular graph requires d < n and an even n * d"
+    );
+    let edges = loop {
+        if let Some(edges) = try_regular(n, d, rng) {
+            break edges;
+        }
+    };
+    let mut builder = EdgeBuilder::new(n);
+    for (u, v) in edges {
+        builder.edge(u, v);
+    }
+    builder.build()
+}
+
+/// Pairs the stubs in rounds, in the manner of Steger and Wormald: each round pairs the remaining stubs at random,
+/// keeps the pairs which form new edges and returns the others to the pool.
+/// Fails if no pair of the remaining stubs could form an edge.
+fn try_regular<R: Rng + ?Sized>(n: usize, d: usize, rng: &mut R) -> Option<Vec<(usize, usize)>> {
+    let mut edges = Vec::with_capacity(n * d / 2);
+    let mut present = HashSet::with_capacity(n * d / 2);
+    let mut stubs = (0..n)
+        .flat_map(|v| std::iter::repeat_n(v, d))
+        .collect::<Vec<_>>();
+    while !stubs.is_empty() {
+        stubs.shuffle(rng);
+        let mut leftover = BTreeMap::new();
+        for pair in stubs.chunks_exact(2) {
+            let (u, v) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            if u != v && present.insert((u, v)) {
+                edges.push((u, v));
+            } else {
+                *leftover.entry(u).or_insert(0) += 1;
+                *leftover.entry(v).or_insert(0) += 1;
+            }
+        }
+        // Continue only if some pair of remaining stubs could still form an edge
+        let remaining = leftover.keys().copied().collect::<Vec<_>>();
+        let suitable = remaining.is_empty()
+            || remaining
+                .iter()
+                .enumerate()
+                .any(|(i, &u)| remaining[..i].iter().any(|&v| !present.contains(&(v, u))));
+        if !suitable {
+            return None;
+        }
+        stubs = leftover
+            .into_iter()
+            .flat_map(|(v, count)| std::iter::repeat_n(v, count))
+            .collect();
+    }
+    Some(edges)
+}
+
+/// The random geometric graph, in which `n` points are placed uniformly in the unit square and joined when
+/// their Euclidean distance is at most `radius`.
+///
+/// Returns the graph along with the position of each vertex.
+pub fn random_geometric<G: FastGraph, R: Rng + ?Sized>(
+    n: usize,
+    radius: f64,
+    rng: &mut R,
+) -> (G, Vec<[f64; 2]>) {
+    let positions = (0..n)
+        .map(|_| [rng.random::<f64>(), rng.random::<f64>()])
+        .collect::<Vec<_>>();
+
+    // Points are bucketed into cells of side at least `radius`, so only neighboring cells need to be compared.
+    // There are at most about `n` cells, so that small radii do not allocate more buckets than points.
+    let cells = if radius > 0.0 {
+        ((1.0 / radius).floor() as usize).clamp(1, n.isqrt().max(1))
+    } else {
+        1
+    };
+    let cell = |p: &[f64; 2]| {
+        (
+            ((p[0] * cells as f64) as usize).min(cells - 1),
+            ((p[1] * cells as f64) as usize).min(cells - 1),
+        )
+    };
+    let mut buckets = vec![vec![]; cells * cells];
+    for (v, p) in positions.iter().enumerate() {
+        let (x, y) = cell(p);
+        buckets[x * cells + y].push(v);
+    }
+
+    let mut builder = EdgeBuilder::new(n);
+    for (v, p) in positions.iter().enumerate() {
+        let (x, y) = cell(p);
+        for nx in x.saturating_sub(1)..(x + 2).min(cells) {
+            for ny in y.saturating_sub(1)..(y + 2).min(cells) {
+                for &u in &buckets[nx * cells + ny] {
+                    let q = &positions[u];
+                    if u < v && (p[0] - q[0]).hypot(p[1] - q[1]) <= radius {
+                        builder.edge(u, v);
+                    }
+                }
+            }
+        }
+    }
+    (builder.build(), positions)
+}
+
+/// The stochastic block model.
+///
+/// Block `i` has `sizes[i]` vertices, labeled consecutively in block order. Vertices in blocks `i` and `j` are
+/// adjacent independently with probability `probabilities[i][j]`.
+///
+/// # Panics
+///
+/// Panics if `probabilities` is not a symmetric matrix with one row per block.
+pub fn stochastic_block_model<G: FastGraph, R: Rng + ?Sized>(
+    sizes: &[usize],
+    probabilities: &[Vec<f64>],
+    rng: &mut R,
+) -> G {
+    let k = sizes.len();
+    assert!(
+        probabilities.len() == k
+            && probabilities.iter().all(|row| row.len() == k)
+            && (0..k).all(|i| (0..i).all(|j| probabilities[i][j] == probabilities[j][i])),
+        "block probabilities must form a symmetric matrix with one row per block"
+    );
+    let mut starts = vec![0; k + 1];
+    for (i, &size) in sizes.iter().enumerate() {
+        starts[i + 1] = starts[i] + size;
+    }
+
+    let mut builder = EdgeBuilder::new(starts[k]);
+    for i in 0..k {
+        sample_within(
+            &mut builder,
+            starts[i]..starts[i + 1],
+            probabilities[i][i],
+            rng,
+        );
+        for j in i + 1..k {
+            sample_between(
+                &mut builder,
+                starts[i]..starts[i + 1],
+                starts[j]..starts[j + 1],
+                probabilities[i][j],
+                rng,
+            );
+        }
+    }
+    builder.build()
+}
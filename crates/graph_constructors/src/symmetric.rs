@@ -0,0 +1,96 @@
+use squareknot_graph::FastGraph;
+
+use crate::EdgeBuilder;
+
+/// The Petersen graph, as the generalized Petersen graph `GP(5, 2)`.
+pub fn petersen<G: FastGraph>() -> G {
+    generalized_petersen(5, 2)
+}
+
+/// The generalized Petersen graph `GP(n, k)`.
+///
+/// The outer cycle is `0..n`, the inner vertices are `n..2n`, vertex `i` is joined to `n + i`,
+/// and `n + i` is joined to `n + (i + k) % n`.
+///
+/// # Panics
+///
+/// Panics unless `n >= 3` and `1 <= k < n / 2`.
+pub fn generalized_petersen<G: FastGraph>(n: usize, k: usize) -> G {
+    assert!(
+        n >= 3 && k >= 1 && 2 * k < n,
+        "GP(n, k) requires n >= 3 and 1 <= k < n / 2"
+    );
+    let mut builder = EdgeBuilder::new(2 * n);
+    for i in 0..n {
+        builder.edge(i, (i + 1) % n);
+        builder.edge(i, n + i);
+        builder.edge(n + i, n + (i + k) % n);
+    }
+    builder.build()
+}
+
+/// The Kneser graph `KG(n, k)`, whose vertices are the `k`-subsets of `0..n`, adjacent when disjoint.
+///
+/// Vertices are ordered by the value of their subsets as bitmasks, so the subset of vertex `i` is
+/// the `i`-th smallest integer below `2^n` with `k` bits set.
+///
+/// # Panics
+///
+/// Panics if `n > 63`.
+pub fn kneser<G: FastGraph>(n: usize, k: usize) -> G {
+    assert!(n <= 63, "Kneser graphs are only supported for n <= 63");
+    let subsets = subsets(n, k);
+    let mut builder = EdgeBuilder::new(subsets.len());
+    for (v, &a) in subsets.iter().enumerate() {
+        for (u, &b) in subsets[..v].iter().enumerate() {
+            if a & b == 0 {
+                builder.edge(u, v);
+            }
+        }
+    }
+    builder.build()
+}
+
+/// Lists the `k`-subsets of `0..n` as increasing bitmasks.
+fn subsets(n: usize, k: usize) -> Vec<u64> {
+    if k > n {
+        return vec![];
+    }
+    if k == 0 {
+        return vec![0];
+    }
+    let mut subsets = vec![];
+    let mut set: u64 = (1 << k) - 1;
+    while set < 1 << n {
+        subsets.push(set);
+        // Gosper's hack: the next integer with the same number of set bits
+        let c = set & set.wrapping_neg();
+        let r = set + c;
+        set = (((r ^ set) >> 2) / c) | r;
+    }
+    subsets
+}
+
+/// The circulant graph on `0..n`, in which `i` is adjacent to `i ± s (mod n)` for each offset `s`.
+/// Offsets which are multiples of `n` are ignored, as are repeated offsets.
+pub fn circulant<G: FastGraph>(n: usize, offsets: &[usize]) -> G {
+    let mut builder = EdgeBuilder::new(n);
+    if n == 0 {
+        return builder.build();
+    }
+    let mut offsets = offsets
+        .iter()
+        .map(|&s| (s % n).min(n - s % n))
+        .filter(|&s| s != 0)
+        .collect::<Vec<_>>();
+    offsets.sort_unstable();
+    offsets.dedup();
+    for s in offsets {
+        // When `2s = n`, `i + s` and `i - s` coincide, so only half of the vertices start an edge
+        let starts = if 2 * s == n { s } else { n };
+        for i in 0..starts {
+            builder.edge(i, (i + s) % n);
+        }
+    }
+    builder.build()
+}
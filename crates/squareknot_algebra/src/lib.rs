@@ -0,0 +1,145 @@
+//! This crate contains matrix representations of graphs and their spectra.
+
+pub mod sparse;
+pub use sparse::SparseMatrix;
+
+mod matrices;
+mod spanning_trees;
+
+use matrices::IndexedGraph;
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+pub use num_bigint::BigUint;
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+/// The matrices which may be built from a graph.
+///
+/// Rows (and the columns of square matrices) are indexed by the position of each vertex in
+/// [`AlgebraicGraph::matrix_vertices`]. Repeated edges and self-loops are ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GraphMatrix {
+    /// `A[u][v]` is one if `v` is a neighbor of `u`.
+    Adjacency,
+    /// The diagonal matrix of (out-)degrees.
+    Degree,
+    /// The combinatorial Laplacian `D - A`.
+    Laplacian,
+    /// The symmetric normalized Laplacian `I - D^(-1/2) A D^(-1/2)`. Rows of isolated vertices are zero.
+    NormalizedLaplacian,
+    /// The signless Laplacian `D + A`.
+    SignlessLaplacian,
+    /// The vertex-edge incidence matrix, with one column per edge.
+    /// Edges present in both directions get a single column.
+    Incidence,
+    /// The incidence matrix with the column of an edge `(u, v)` holding `1` at `u` and `-1` at `v`,
+    /// so that for undirected graphs its product with its own transpose is the Laplacian.
+    OrientedIncidence,
+}
+
+/// Trait implementing matrix constructions on graphs with `usize` vertices.
+///
+/// The spectral methods assume that the view is undirected, so that the matrices involved are symmetric.
+pub trait AlgebraicGraph: ViewCombinator<VertexLabel = usize> + ExactCombinator {
+    /// Lists the vertices in the order used to index the rows of every matrix.
+    fn matrix_vertices(&self) -> Vec<usize> {
+        IndexedGraph::build(self).vertices
+    }
+
+    fn matrix(&self, kind: GraphMatrix) -> DMatrix<f64> {
+        self.sparse_matrix(kind).to_dense()
+    }
+
+    fn sparse_matrix(&self, kind: GraphMatrix) -> SparseMatrix {
+        IndexedGraph::build(self).matrix(kind)
+    }
+
+    fn adjacency_matrix(&self) -> DMatrix<f64> {
+        self.matrix(GraphMatrix::Adjacency)
+    }
+
+    fn laplacian_matrix(&self) -> DMatrix<f64> {
+        self.matrix(GraphMatrix::Laplacian)
+    }
+
+    /// Computes the eigenvalues of a square matrix of the graph in increasing order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kind` is an incidence matrix, which is not square.
+    fn eigenvalues(&self, kind: GraphMatrix) -> Vec<f64> {
+        assert!(
+            !matches!(
+                kind,
+                GraphMatrix::Incidence | GraphMatrix::OrientedIncidence
+            ),
+            "incidence matrices have no eigenvalues"
+        );
+        let mut eigenvalues = self
+            .matrix(kind)
+            .symmetric_eigenvalues()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        eigenvalues.sort_by(f64::total_cmp);
+        eigenvalues
+    }
+
+    /// Computes the second smallest eigenvalue of the Laplacian, which is positive exactly when the graph is connected.
+    /// Returns zero for graphs with fewer than two vertices.
+    fn algebraic_connectivity(&self) -> f64 {
+        self.eigenvalues(GraphMatrix::Laplacian)
+            .get(1)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Computes a unit eigenvector of the Laplacian for the algebraic connectivity, indexed as in [`Self::matrix_vertices`].
+    /// The signs of its entries give a spectral bisection of the graph.
+    /// Returns `None` for graphs with fewer than two vertices.
+    fn fiedler_vector(&self) -> Option<DVector<f64>> {
+        let laplacian = self.laplacian_matrix();
+        if laplacian.nrows() < 2 {
+            return None;
+        }
+        let eigen = SymmetricEigen::new(laplacian);
+        let mut order = (0..eigen.eigenvalues.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| eigen.eigenvalues[a].total_cmp(&eigen.eigenvalues[b]));
+        Some(eigen.eigenvectors.column(order[1]).into_owned())
+    }
+
+    /// Counts the spanning trees of an undirected graph exactly, as a cofactor of the Laplacian (Kirchhoff's theorem).
+    /// This is zero for disconnected graphs, including the graph with no vertices.
+    fn spanning_tree_count(&self) -> BigUint {
+        spanning_trees::spanning_tree_count(&IndexedGraph::build(self))
+    }
+
+    /// Computes the natural logarithm of [`Self::spanning_tree_count`] in floating point,
+    /// which is far cheaper for large graphs. Disconnected graphs give negative infinity.
+    fn log_spanning_tree_count(&self) -> f64 {
+        spanning_trees::log_spanning_tree_count(&IndexedGraph::build(self))
+    }
+
+    /// Counts the spanning arborescences of a directed graph with every arc directed away from `root` (Tutte's theorem).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `root` is not a vertex of the graph.
+    fn spanning_arborescence_count(&self, root: usize) -> BigUint {
+        let graph = IndexedGraph::build(self);
+        let root = graph.row_of(root);
+        spanning_trees::arborescence_count(&graph, root)
+    }
+
+    /// Computes the natural logarithm of [`Self::spanning_arborescence_count`] in floating point.
+    /// Gives negative infinity if some vertex is unreachable from `root`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `root` is not a vertex of the graph.
+    fn log_spanning_arborescence_count(&self, root: usize) -> f64 {
+        let graph = IndexedGraph::build(self);
+        let root = graph.row_of(root);
+        spanning_trees::log_arborescence_count(&graph, root)
+    }
+}
+
+impl<G: ViewCombinator<VertexLabel = usize> + ExactCombinator> AlgebraicGraph for G {}
@@ -0,0 +1,136 @@
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+use crate::{GraphMatrix, SparseMatrix};
+
+/// The vertices of a view in row order, along with their deduplicated neighborhoods.
+pub(crate) struct IndexedGraph {
+    pub(crate) vertices: Vec<usize>,
+    /// The sorted row indices of the neighbors of each row, excluding the row itself.
+    pub(crate) neighbors: Vec<Vec<usize>>,
+}
+
+impl IndexedGraph {
+    pub(crate) fn build<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+        graph: &G,
+    ) -> Self {
+        let mut index = vec![usize::MAX; graph.num_v_labels()];
+        let mut vertices = vec![];
+        for v in graph.vertex_iterator() {
+            if index[v] == usize::MAX {
+                index[v] = vertices.len();
+                vertices.push(v);
+            }
+        }
+
+        let neighbors = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| {
+                let mut row = graph
+                    .neighbor_iterator(v)
+                    .into_iter()
+                    .flatten()
+                    .map(|w| index[w])
+                    .filter(|&j| j != usize::MAX && j != i)
+                    .collect::<Vec<_>>();
+                row.sort_unstable();
+                row.dedup();
+                row
+            })
+            .collect();
+
+        Self {
+            vertices,
+            neighbors,
+        }
+    }
+
+    /// Returns the row of the vertex `label`.
+    pub(crate) fn row_of(&self, label: usize) -> usize {
+        self.vertices
+            .iter()
+            .position(|&v| v == label)
+            .expect("the root must be a vertex of the graph")
+    }
+
+    fn degrees(&self) -> Vec<f64> {
+        self.neighbors.iter().map(|row| row.len() as f64).collect()
+    }
+
+    /// Lists each edge once, oriented from its smaller row unless only the reverse arc is present.
+    fn edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = vec![];
+        for (i, row) in self.neighbors.iter().enumerate() {
+            for &j in row {
+                if i < j || self.neighbors[j].binary_search(&i).is_err() {
+                    edges.push((i, j));
+                }
+            }
+        }
+        edges
+    }
+
+    pub(crate) fn matrix(&self, kind: GraphMatrix) -> SparseMatrix {
+        let n = self.vertices.len();
+        let adjacency = || {
+            self.neighbors
+                .iter()
+                .enumerate()
+                .flat_map(|(i, row)| row.iter().map(move |&j| (i, j)))
+        };
+
+        match kind {
+            GraphMatrix::Adjacency => {
+                SparseMatrix::from_triplets(n, n, adjacency().map(|(i, j)| (i, j, 1.0)))
+            }
+            GraphMatrix::Degree => SparseMatrix::from_triplets(
+                n,
+                n,
+                self.degrees()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, d)| (i, i, d)),
+            ),
+            GraphMatrix::Laplacian | GraphMatrix::SignlessLaplacian => {
+                let sign = if kind == GraphMatrix::Laplacian {
+                    -1.0
+                } else {
+                    1.0
+                };
+                let diagonal = self
+                    .degrees()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, d)| (i, i, d));
+                let off_diagonal = adjacency().map(|(i, j)| (i, j, sign));
+                SparseMatrix::from_triplets(n, n, diagonal.chain(off_diagonal))
+            }
+            GraphMatrix::NormalizedLaplacian => {
+                let scale = self
+                    .degrees()
+                    .into_iter()
+                    .map(|d| if d > 0.0 { d.sqrt().recip() } else { 0.0 })
+                    .collect::<Vec<_>>();
+                let diagonal = (0..n).filter(|&i| scale[i] > 0.0).map(|i| (i, i, 1.0));
+                let off_diagonal = adjacency().map(|(i, j)| (i, j, -scale[i] * scale[j]));
+                SparseMatrix::from_triplets(n, n, diagonal.chain(off_diagonal))
+            }
+            GraphMatrix::Incidence | GraphMatrix::OrientedIncidence => {
+                let head = if kind == GraphMatrix::Incidence {
+                    1.0
+                } else {
+                    -1.0
+                };
+                let edges = self.edges();
+                SparseMatrix::from_triplets(
+                    n,
+                    edges.len(),
+                    edges
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(e, &(i, j))| [(i, e, 1.0), (j, e, head)]),
+                )
+            }
+        }
+    }
+}
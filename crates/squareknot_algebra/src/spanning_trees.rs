@@ -0,0 +1,128 @@
+use nalgebra::{Cholesky, DMatrix, LU};
+use num_bigint::{BigInt, BigUint};
+
+use crate::matrices::IndexedGraph;
+
+/// Builds the Laplacian with the row and column of `root` deleted.
+///
+/// Undirected graphs use the degree Laplacian, while for arborescences the diagonal holds in-degrees,
+/// so that the minor counts the arborescences directed away from `root`.
+fn reduced_laplacian(graph: &IndexedGraph, root: usize, in_degrees: bool) -> Vec<Vec<i64>> {
+    let n = graph.vertices.len();
+    let mut laplacian = vec![vec![0i64; n]; n];
+    for (u, row) in graph.neighbors.iter().enumerate() {
+        for &v in row {
+            laplacian[u][v] -= 1;
+            if in_degrees {
+                laplacian[v][v] += 1;
+            } else {
+                laplacian[u][u] += 1;
+            }
+        }
+    }
+    laplacian.remove(root);
+    for row in &mut laplacian {
+        row.remove(root);
+    }
+    laplacian
+}
+
+/// Computes a determinant exactly with Bareiss' fraction-free elimination.
+/// Every intermediate value is a minor of the input, so the entries never grow beyond the determinant's size.
+fn bareiss_determinant(matrix: Vec<Vec<i64>>) -> BigInt {
+    let n = matrix.len();
+    let mut matrix = matrix
+        .into_iter()
+        .map(|row| row.into_iter().map(BigInt::from).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut sign = 1;
+    let mut previous = BigInt::from(1);
+
+    for k in 0..n {
+        let Some(pivot) = (k..n).find(|&r| matrix[r][k] != BigInt::ZERO) else {
+            return BigInt::ZERO;
+        };
+        if pivot != k {
+            matrix.swap(pivot, k);
+            sign = -sign;
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let value = &matrix[i][j] * &matrix[k][k] - &matrix[i][k] * &matrix[k][j];
+                matrix[i][j] = value / &previous;
+            }
+        }
+        previous = matrix[k][k].clone();
+    }
+
+    match n {
+        0 => BigInt::from(1),
+        _ => sign * previous,
+    }
+}
+
+/// Returns true if every row can be reached from `root` by following neighbors.
+fn reaches_all(graph: &IndexedGraph, root: usize) -> bool {
+    let mut seen = vec![false; graph.vertices.len()];
+    seen[root] = true;
+    let mut stack = vec![root];
+    let mut reached = 1;
+    while let Some(u) = stack.pop() {
+        for &v in &graph.neighbors[u] {
+            if !seen[v] {
+                seen[v] = true;
+                reached += 1;
+                stack.push(v);
+            }
+        }
+    }
+    reached == graph.vertices.len()
+}
+
+fn to_dense(matrix: &[Vec<i64>]) -> DMatrix<f64> {
+    let n = matrix.len();
+    DMatrix::from_fn(n, n, |i, j| matrix[i][j] as f64)
+}
+
+pub(crate) fn spanning_tree_count(graph: &IndexedGraph) -> BigUint {
+    if graph.vertices.is_empty() {
+        return BigUint::ZERO;
+    }
+    let determinant = bareiss_determinant(reduced_laplacian(graph, 0, false));
+    determinant.to_biguint().unwrap()
+}
+
+pub(crate) fn arborescence_count(graph: &IndexedGraph, root: usize) -> BigUint {
+    let determinant = bareiss_determinant(reduced_laplacian(graph, root, true));
+    determinant.to_biguint().unwrap()
+}
+
+pub(crate) fn log_spanning_tree_count(graph: &IndexedGraph) -> f64 {
+    // Rounding can make the reduced Laplacian of a disconnected graph look positive definite,
+    // so connectivity is checked exactly first
+    if graph.vertices.is_empty() || !reaches_all(graph, 0) {
+        return f64::NEG_INFINITY;
+    }
+    match Cholesky::new(to_dense(&reduced_laplacian(graph, 0, false))) {
+        Some(cholesky) => {
+            2.0 * cholesky
+                .l_dirty()
+                .diagonal()
+                .iter()
+                .map(|x| x.ln())
+                .sum::<f64>()
+        }
+        None => f64::NEG_INFINITY,
+    }
+}
+
+pub(crate) fn log_arborescence_count(graph: &IndexedGraph, root: usize) -> f64 {
+    if !reaches_all(graph, root) {
+        return f64::NEG_INFINITY;
+    }
+    let lu = LU::new(to_dense(&reduced_laplacian(graph, root, true)));
+    if !lu.is_invertible() {
+        return f64::NEG_INFINITY;
+    }
+    lu.u().diagonal().iter().map(|x| x.abs().ln()).sum()
+}
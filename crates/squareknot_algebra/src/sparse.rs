@@ -0,0 +1,108 @@
+use nalgebra::{DMatrix, DVector};
+
+/// A matrix in compressed sparse row form.
+///
+/// Only nonzero entries are stored, with the entries of each row sorted by column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix {
+    nrows: usize,
+    ncols: usize,
+    /// The entries of row `r` are at `row_offsets[r]..row_offsets[r + 1]`.
+    row_offsets: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /// Constructs a matrix from `(row, column, value)` triplets. Repeated positions are summed.
+    pub fn from_triplets<I: IntoIterator<Item = (usize, usize, f64)>>(
+        nrows: usize,
+        ncols: usize,
+        triplets: I,
+    ) -> Self {
+        let mut triplets = triplets.into_iter().collect::<Vec<_>>();
+        triplets.sort_by_key(|&(r, c, _)| (r, c));
+
+        let mut row_offsets = vec![0; nrows + 1];
+        let mut columns: Vec<usize> = vec![];
+        let mut values: Vec<f64> = vec![];
+        let mut last = None;
+        for (r, c, value) in triplets {
+            assert!(
+                r < nrows && c < ncols,
+                "entry ({r}, {c}) is out of bounds for a {nrows}x{ncols} matrix"
+            );
+            if last == Some((r, c)) {
+                *values.last_mut().unwrap() += value;
+            } else {
+                row_offsets[r + 1] += 1;
+                columns.push(c);
+                values.push(value);
+                last = Some((r, c));
+            }
+        }
+        for r in 0..nrows {
+            row_offsets[r + 1] += row_offsets[r];
+        }
+
+        Self {
+            nrows,
+            ncols,
+            row_offsets,
+            columns,
+            values,
+        }
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the entry at `(row, column)`, which is zero if it is not stored.
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        match self.columns[range.clone()].binary_search(&column) {
+            Ok(i) => self.values[range.start + i],
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Iterate over the stored entries of `row` as `(column, value)` pairs.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        self.columns[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// Iterate over the stored entries as `(row, column, value)` triplets, in row-major order.
+    pub fn triplet_iter(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        (0..self.nrows).flat_map(move |r| self.row(r).map(move |(c, value)| (r, c, value)))
+    }
+
+    /// Multiplies the matrix by a vector.
+    pub fn mul_vector(&self, vector: &DVector<f64>) -> DVector<f64> {
+        assert_eq!(vector.len(), self.ncols, "dimension mismatch");
+        DVector::from_fn(self.nrows, |r, _| {
+            self.row(r).map(|(c, value)| value * vector[c]).sum()
+        })
+    }
+
+    pub fn to_dense(&self) -> DMatrix<f64> {
+        let mut dense = DMatrix::zeros(self.nrows, self.ncols);
+        for (r, c, value) in self.triplet_iter() {
+            dense[(r, c)] = value;
+        }
+        dense
+    }
+}
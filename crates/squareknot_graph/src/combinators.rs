@@ -10,11 +10,19 @@ pub use vertex_compactor::*;
 pub mod edge_filter;
 pub use edge_filter::*;
 
+pub mod edge_map;
+pub use edge_map::*;
+
 pub mod graph_view;
 pub use graph_view::*;
 
+pub mod transpose_view;
+pub use transpose_view::*;
+
 use std::hash::Hash;
 
+use crate::{FastGraph, LabelMap, SimpleGraph, Weight};
+
 /// View Combinators
 pub trait ViewCombinator: Sized {
     type VertexLabel: Copy + Hash + Eq;
@@ -42,6 +50,18 @@ pub trait ViewCombinator: Sized {
         EdgeFilter::build(self, f)
     }
 
+    /// Replaces every edge `(u, v)` with `f(&(u, v))`, e.g. to reverse, redirect or relabel edges.
+    /// Mapped edges with an endpoint outside the view are dropped.
+    /// The result is unweighted, see [`WeightedViewCombinator::map_weighted_edges`] to keep weights.
+    fn map_edges<
+        F: Fn(&(Self::VertexLabel, Self::VertexLabel)) -> (Self::VertexLabel, Self::VertexLabel),
+    >(
+        self,
+        f: F,
+    ) -> EdgeMap<Self, F> {
+        EdgeMap::build(self, f)
+    }
+
     fn filter_vertices<F: Fn(&Self::VertexLabel) -> bool>(self, f: F) -> VertexFilter<Self, F> {
         VertexFilter::build(self, f)
     }
@@ -56,8 +76,80 @@ pub trait ViewCombinator: Sized {
     fn compact(self) -> VertexCompactor<Self> {
         VertexCompactor::build(self)
     }
+
+    /// Copies the view into an owned undirected graph whose labels are `0..n`,
+    /// along with the map between those labels and the labels of the view.
+    /// Traversals of the materialized graph avoid re-evaluating the closures of the view at every step.
+    fn materialize(&self) -> (SimpleGraph, LabelMap<Self::VertexLabel>) {
+        self.materialize_into()
+    }
+
+    /// Like [`ViewCombinator::materialize`], but builds any graph type, such as a directed graph.
+    fn materialize_into<G: FastGraph>(&self) -> (G, LabelMap<Self::VertexLabel>) {
+        let labels = LabelMap::from_labels(self.vertex_iterator());
+        let mut graph = G::empty(labels.len());
+        for (u, v) in self.edge_iterator() {
+            if let (Some(u), Some(v)) = (labels.index(&u), labels.index(&v)) {
+                graph.add_edge(u, v);
+            }
+        }
+        (graph, labels)
+    }
 }
 
 pub trait ExactCombinator: ViewCombinator {
     fn num_v_labels(&self) -> usize;
+
+    /// Copies the view into a new graph with the same labels.
+    /// Labels which are not vertices of the view become isolated vertices, so [`ViewCombinator::compact`] the view first to drop them.
+    fn collect_into<G: FastGraph>(&self) -> G
+    where
+        Self: ViewCombinator<VertexLabel = usize>,
+    {
+        let mut graph = G::empty(self.num_v_labels());
+        for (u, v) in self.edge_iterator() {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    /// Collects the vertices of the view, without duplicates.
+    fn distinct_vertices(&self) -> Vec<usize>
+    where
+        Self: ViewCombinator<VertexLabel = usize>,
+    {
+        let mut seen = vec![false; self.num_v_labels()];
+        self.vertex_iterator()
+            .filter(|&v| !std::mem::replace(&mut seen[v], true))
+            .collect()
+    }
+}
+
+/// View Combinators over graphs with weighted edges
+///
+/// Combinators which do not alter edges (e.g. [`EdgeFilter`] and [`VertexFilter`]) preserve weights.
+pub trait WeightedViewCombinator: ViewCombinator {
+    type Weight: Weight;
+
+    /// Iterates over edges by label, along with their weights.
+    /// Consumers of this should expect to handle duplicate edges.
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a;
+
+    /// Iterates over neighbors of `vertex` by label, along with the weight of the connecting edge.
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a>;
+
+    /// Like [`ViewCombinator::map_edges`], but every mapped edge keeps its weight.
+    fn map_weighted_edges<
+        F: Fn(&(Self::VertexLabel, Self::VertexLabel)) -> (Self::VertexLabel, Self::VertexLabel),
+    >(
+        self,
+        f: F,
+    ) -> EdgeMap<Self, F, Self::Weight> {
+        EdgeMap::build_weighted(self, f)
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{ExactCombinator, ViewCombinator};
+use crate::{ExactCombinator, ViewCombinator, WeightedViewCombinator};
 
 pub struct EdgeFilter<G: ViewCombinator, F: Fn(&(G::VertexLabel, G::VertexLabel)) -> bool> {
     preimage: G,
@@ -50,3 +50,26 @@ impl<
         self.preimage.num_v_labels()
     }
 }
+
+impl<G: WeightedViewCombinator, F: Fn(&(G::VertexLabel, G::VertexLabel)) -> bool>
+    WeightedViewCombinator for EdgeFilter<G, F>
+{
+    type Weight = G::Weight;
+
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a {
+        self.preimage
+            .weighted_edge_iterator()
+            .filter(|&(u, v, _)| (self.f)(&(u, v)))
+    }
+
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a> {
+        self.preimage
+            .weighted_neighbor_iterator(vertex)
+            .map(move |neigh| neigh.filter(move |&(v, _)| (self.f)(&(vertex, v))))
+    }
+}
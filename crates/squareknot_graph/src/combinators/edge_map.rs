@@ -1,49 +1,145 @@
-use crate::{ExactCombinator, GraphCombinator};
+use std::collections::HashMap;
 
+use crate::{ExactCombinator, ViewCombinator, WeightedViewCombinator};
+
+/// Replaces every edge `(u, v)` of the preimage with the edge `f(&(u, v))`, keeping the vertices.
+///
+/// Edges mapped to self-loops, or with an endpoint which is not a vertex of the preimage, are dropped.
+/// The edges of an undirected preimage are mapped in both orientations.
+///
+/// Since the neighbors of a vertex may come from any edge of the preimage,
+/// the mapped neighborhoods are indexed once when the view is built, which maps every edge of the preimage.
+/// Each neighbor is stored along with a `W`, which is the weight of its edge when built with
+/// [`WeightedViewCombinator::map_weighted_edges`], and `()` otherwise.
 pub struct EdgeMap<
-    'g,
-    G: GraphCombinator,
-    F: Fn(&(G::VertexLabel, G::VertexLabel)) -> !,
+    G: ViewCombinator,
+    F: Fn(&(G::VertexLabel, G::VertexLabel)) -> (G::VertexLabel, G::VertexLabel),
+    W: Copy = (),
 > {
-    preimage: &'g G,
+    preimage: G,
     f: F,
+    neighbors: HashMap<G::VertexLabel, Vec<(G::VertexLabel, W)>>,
 }
 
-impl<'g, G: GraphCombinator, F: Fn(&(G::VertexLabel, G::VertexLabel)) -> bool> EdgeFilter<'g, G, F> {
-    pub fn build(preimage: &'g G, filter: F) -> Self {
-        Self {
+impl<
+        G: ViewCombinator,
+        F: Fn(&(G::VertexLabel, G::VertexLabel)) -> (G::VertexLabel, G::VertexLabel),
+    > EdgeMap<G, F>
+{
+    pub fn build(preimage: G, map: F) -> Self {
+        let edges = preimage.edge_iterator().map(|(u, v)| (u, v, ())).collect();
+        Self::index(preimage, map, edges)
+    }
+}
+
+impl<
+        G: WeightedViewCombinator,
+        F: Fn(&(G::VertexLabel, G::VertexLabel)) -> (G::VertexLabel, G::VertexLabel),
+    > EdgeMap<G, F, G::Weight>
+{
+    /// Builds the view keeping the weight of every edge.
+    pub fn build_weighted(preimage: G, map: F) -> Self {
+        let edges = preimage.weighted_edge_iterator().collect();
+        Self::index(preimage, map, edges)
+    }
+}
+
+impl<
+        G: ViewCombinator,
+        F: Fn(&(G::VertexLabel, G::VertexLabel)) -> (G::VertexLabel, G::VertexLabel),
+        W: Copy,
+    > EdgeMap<G, F, W>
+{
+    /// Maps the `edges` of the preimage, storing the kept ones in the neighborhoods of their sources.
+    fn index(preimage: G, f: F, edges: Vec<(G::VertexLabel, G::VertexLabel, W)>) -> Self {
+        let mut neighbors = HashMap::new();
+        for v in preimage.vertex_iterator() {
+            neighbors.entry(v).or_insert_with(Vec::new);
+        }
+
+        let mut edge_map = Self {
             preimage,
-            f: filter
+            f,
+            neighbors,
+        };
+        for (u, v, w) in edges {
+            let (u, v) = (edge_map.f)(&(u, v));
+            if edge_map.keeps(u, v) {
+                edge_map.neighbors.get_mut(&u).unwrap().push((v, w));
+            }
         }
+        edge_map
+    }
+
+    /// Returns true if the mapped edge is kept, i.e. it is not a self-loop and both endpoints are vertices.
+    fn keeps(&self, u: G::VertexLabel, v: G::VertexLabel) -> bool {
+        u != v && self.neighbors.contains_key(&u) && self.neighbors.contains_key(&v)
     }
 }
 
-impl<'g, G: GraphCombinator, F: Fn(&(G::VertexLabel, G::VertexLabel)) -> bool> GraphCombinator
-    for EdgeFilter<'g, G, F>
+impl<
+        G: ViewCombinator,
+        F: Fn(&(G::VertexLabel, G::VertexLabel)) -> (G::VertexLabel, G::VertexLabel),
+        W: Copy,
+    > ViewCombinator for EdgeMap<G, F, W>
 {
     type VertexLabel = G::VertexLabel;
 
     fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = Self::VertexLabel> + 'a {
-        /* self.preimage.vertex_iterator()
-            .filter(|&v| (self.f)(v))*/
         self.preimage.vertex_iterator()
     }
 
     fn edge_iterator<'a>(
         &'a self,
     ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel)> + 'a {
-        /* self.preimage
+        self.preimage
             .edge_iterator()
-            .filter(|&(u, v)| (self.f)(u) && (self.f)(v))*/
-        self.preimage.edge_iterator()
-            .filter(|e| (self.f)(e))
+            .map(|e| (self.f)(&e))
+            .filter(|&(u, v)| self.keeps(u, v))
+    }
+
+    fn neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a> {
+        Some(self.neighbors.get(&vertex)?.iter().map(|&(v, _)| v))
     }
 }
 
-impl<'g, G: GraphCombinator<VertexLabel = usize> + ExactCombinator, F: Fn(&(G::VertexLabel, G::VertexLabel)) -> bool> ExactCombinator
-    for EdgeFilter<'g, G, F>
+impl<
+        G: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+        F: Fn(&(G::VertexLabel, G::VertexLabel)) -> (G::VertexLabel, G::VertexLabel),
+        W: Copy,
+    > ExactCombinator for EdgeMap<G, F, W>
 {
     fn num_v_labels(&self) -> usize {
         self.preimage.num_v_labels()
     }
 }
+
+impl<
+        G: WeightedViewCombinator,
+        F: Fn(&(G::VertexLabel, G::VertexLabel)) -> (G::VertexLabel, G::VertexLabel),
+    > WeightedViewCombinator for EdgeMap<G, F, G::Weight>
+{
+    type Weight = G::Weight;
+
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a {
+        self.preimage
+            .weighted_edge_iterator()
+            .map(|(u, v, w)| {
+                let (u, v) = (self.f)(&(u, v));
+                (u, v, w)
+            })
+            .filter(|&(u, v, _)| self.keeps(u, v))
+    }
+
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a> {
+        Some(self.neighbors.get(&vertex)?.iter().copied())
+    }
+}
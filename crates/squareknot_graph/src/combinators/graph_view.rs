@@ -1,8 +1,8 @@
 use std::hash::Hash;
 
-use crate::AbstractGraph;
+use crate::{AbstractGraph, WeightedGraph};
 
-use crate::{ExactCombinator, ViewCombinator};
+use crate::{ExactCombinator, ViewCombinator, WeightedViewCombinator};
 
 pub struct GraphView<'g, G: AbstractGraph> {
     graph: &'g G,
@@ -40,3 +40,22 @@ impl<G: AbstractGraph<VertexLabel = V>, V: Copy + Hash + Eq> ExactCombinator for
         self.graph.num_v_labels()
     }
 }
+
+impl<G: WeightedGraph<VertexLabel = V>, V: Copy + Hash + Eq> WeightedViewCombinator
+    for GraphView<'_, G>
+{
+    type Weight = G::Weight;
+
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a {
+        self.graph.weighted_edge_iterator()
+    }
+
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a> {
+        self.graph.weighted_neighbor_iterator(vertex)
+    }
+}
@@ -0,0 +1,68 @@
+use std::hash::Hash;
+
+use crate::{DirectedGraph, WeightedDirectedGraph};
+
+use crate::{ExactCombinator, ViewCombinator, WeightedViewCombinator};
+
+/// A view of a directed graph with every arc reversed.
+pub struct TransposeView<'g, G: DirectedGraph> {
+    graph: &'g G,
+}
+
+impl<'g, G: DirectedGraph> TransposeView<'g, G> {
+    pub fn build(graph: &'g G) -> Self {
+        Self { graph }
+    }
+}
+
+impl<G: DirectedGraph<VertexLabel = V>, V: Copy + Hash + Eq> ViewCombinator
+    for TransposeView<'_, G>
+{
+    type VertexLabel = V;
+
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = Self::VertexLabel> + 'a {
+        self.graph.vertex_iterator()
+    }
+
+    fn edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel)> + 'a {
+        self.graph.edge_iterator().map(|(u, v)| (v, u))
+    }
+
+    fn neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a> {
+        self.graph.in_neighbor_iterator(vertex)
+    }
+}
+
+impl<G: DirectedGraph<VertexLabel = V>, V: Copy + Hash + Eq> ExactCombinator
+    for TransposeView<'_, G>
+{
+    fn num_v_labels(&self) -> usize {
+        self.graph.num_v_labels()
+    }
+}
+
+impl<G: WeightedDirectedGraph<VertexLabel = V>, V: Copy + Hash + Eq> WeightedViewCombinator
+    for TransposeView<'_, G>
+{
+    type Weight = G::Weight;
+
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a {
+        self.graph
+            .weighted_edge_iterator()
+            .map(|(u, v, w)| (v, u, w))
+    }
+
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a> {
+        self.graph.weighted_in_neighbor_iterator(vertex)
+    }
+}
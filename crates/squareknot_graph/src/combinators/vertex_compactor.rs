@@ -1,13 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{ExactCombinator, ViewCombinator};
+use crate::{ExactCombinator, ViewCombinator, WeightedViewCombinator};
 
 pub struct VertexCompactor<G: ViewCombinator> {
     preimage: G,
     map: HashMap<G::VertexLabel, usize>,
 }
 
-impl<'g, G: ViewCombinator> VertexCompactor<G> {
+impl<G: ViewCombinator> VertexCompactor<G> {
     pub fn build(preimage: G) -> Self {
         let mut map = HashMap::new();
 
@@ -40,11 +40,7 @@ impl<G: ViewCombinator> ViewCombinator for VertexCompactor<G> {
         &'a self,
         vertex: Self::VertexLabel,
     ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a> {
-        let key = self.map.iter().filter(|&(_, &v)| v == vertex).next();
-        if let None = key {
-            return None;
-        }
-        let k = key.unwrap().0;
+        let (k, _) = self.map.iter().find(|&(_, &v)| v == vertex)?;
         self.preimage
             .neighbor_iterator(*k)
             .map(|neigh| neigh.map(|v| self.map[&v]))
@@ -56,3 +52,25 @@ impl<G: ViewCombinator> ExactCombinator for VertexCompactor<G> {
         self.map.len()
     }
 }
+
+impl<G: WeightedViewCombinator> WeightedViewCombinator for VertexCompactor<G> {
+    type Weight = G::Weight;
+
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a {
+        self.preimage
+            .weighted_edge_iterator()
+            .map(|(u, v, w)| (self.map[&u], self.map[&v], w))
+    }
+
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a> {
+        let (k, _) = self.map.iter().find(|&(_, &v)| v == vertex)?;
+        self.preimage
+            .weighted_neighbor_iterator(*k)
+            .map(|neigh| neigh.map(|(v, w)| (self.map[&v], w)))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ExactCombinator, ViewCombinator};
+use crate::{ExactCombinator, ViewCombinator, WeightedViewCombinator};
 
 pub struct VertexFilter<G: ViewCombinator, F: Fn(&G::VertexLabel) -> bool> {
     preimage: G,
@@ -50,3 +50,30 @@ impl<G: ViewCombinator + ExactCombinator, F: Fn(&G::VertexLabel) -> bool> ExactC
         self.preimage.num_v_labels()
     }
 }
+
+impl<G: WeightedViewCombinator, F: Fn(&G::VertexLabel) -> bool> WeightedViewCombinator
+    for VertexFilter<G, F>
+{
+    type Weight = G::Weight;
+
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a {
+        self.preimage
+            .weighted_edge_iterator()
+            .filter(|&(u, v, _)| (self.f)(&u) && (self.f)(&v))
+    }
+
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a> {
+        if (self.f)(&vertex) {
+            self.preimage
+                .weighted_neighbor_iterator(vertex)
+                .map(|neigh| neigh.filter(|(v, _)| (self.f)(v)))
+        } else {
+            None
+        }
+    }
+}
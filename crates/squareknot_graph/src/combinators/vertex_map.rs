@@ -7,7 +7,7 @@ pub struct VertexMap<G: ViewCombinator, V: Copy + Hash + Eq, F: Fn(&G::VertexLab
     f: F,
 }
 
-impl<'g, G: ViewCombinator, V: Copy + Hash + Eq, F: Fn(&G::VertexLabel) -> V> VertexMap<G, V, F> {
+impl<G: ViewCombinator, V: Copy + Hash + Eq, F: Fn(&G::VertexLabel) -> V> VertexMap<G, V, F> {
     pub fn build(preimage: G, map: F) -> Self {
         Self { preimage, f: map }
     }
@@ -55,7 +55,7 @@ impl<G: ViewCombinator, V: Copy + Hash + Eq, F: Fn(&G::VertexLabel) -> V> ViewCo
     }
 }
 
-impl<'g, G: ViewCombinator + ExactCombinator, F: Fn(&G::VertexLabel) -> usize> ExactCombinator
+impl<G: ViewCombinator + ExactCombinator, F: Fn(&G::VertexLabel) -> usize> ExactCombinator
     for VertexMap<G, usize, F>
 {
     fn num_v_labels(&self) -> usize {
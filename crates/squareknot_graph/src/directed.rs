@@ -0,0 +1,294 @@
+use crate::{
+    AbstractGraph, DirectedGraph, FastGraph, Storage, UnweightedStorage, Weight,
+    WeightedAdjacencyList, WeightedDirectedGraph, WeightedGraph, WeightedStorage,
+};
+
+/// A directed graph with a generic storage mechanism.
+///
+/// Arcs are stored twice, once in each direction, so that both out-neighbors and in-neighbors may be iterated efficiently.
+pub struct DiGraph<S: Storage> {
+    order: usize,
+    size: usize,
+    out_storage: S,
+    in_storage: S,
+}
+
+impl<S: Storage> DiGraph<S> {
+    /// Constructs a graph from existing storage of out-neighbors and in-neighbors.
+    /// `in_storage` must hold the reverse of every arc in `out_storage`.
+    pub fn from_storage(out_storage: S, in_storage: S) -> Self {
+        Self {
+            order: out_storage.vertex_iterator().count(),
+            size: out_storage.edge_iterator().count(),
+            out_storage,
+            in_storage,
+        }
+    }
+
+    /// Returns the storage of out-neighbors.
+    pub fn out_storage(&self) -> &S {
+        &self.out_storage
+    }
+
+    /// Returns the storage of in-neighbors.
+    pub fn in_storage(&self) -> &S {
+        &self.in_storage
+    }
+}
+
+impl<S: Storage> DiGraph<S> {
+    /// Rebuilds the graph on new storage. Repeated arcs of the source are merged, so the size is recounted.
+    fn convert<T: Storage>(&self, out_storage: T, in_storage: T) -> DiGraph<T> {
+        DiGraph {
+            order: self.order,
+            size: out_storage.edge_iterator().count(),
+            out_storage,
+            in_storage,
+        }
+    }
+}
+
+/// Converts between storage backends, e.g. from an adjacency list to [`crate::Csr`].
+/// Weights of the source are dropped.
+impl<S: Storage, T: UnweightedStorage> From<&DiGraph<S>> for DiGraph<T> {
+    fn from(graph: &DiGraph<S>) -> Self {
+        graph.convert(
+            T::from_storage(&graph.out_storage),
+            T::from_storage(&graph.in_storage),
+        )
+    }
+}
+
+/// Converts between weighted storage backends, keeping the weight of every arc.
+impl<W: Weight, S: WeightedStorage<Weight = W>> From<&DiGraph<S>>
+    for DiGraph<WeightedAdjacencyList<W>>
+{
+    fn from(graph: &DiGraph<S>) -> Self {
+        graph.convert(
+            WeightedAdjacencyList::from_weighted_storage(&graph.out_storage),
+            WeightedAdjacencyList::from_weighted_storage(&graph.in_storage),
+        )
+    }
+}
+
+/// Converts between weighted storage backends, keeping the weight of every arc.
+#[cfg(feature = "nalgebra")]
+impl<W: Weight, S: WeightedStorage<Weight = W>> From<&DiGraph<S>>
+    for DiGraph<crate::WeightedAdjacencyMatrix<W>>
+{
+    fn from(graph: &DiGraph<S>) -> Self {
+        graph.convert(
+            crate::WeightedAdjacencyMatrix::from_weighted_storage(&graph.out_storage),
+            crate::WeightedAdjacencyMatrix::from_weighted_storage(&graph.in_storage),
+        )
+    }
+}
+
+impl<S: Storage> AbstractGraph for DiGraph<S> {
+    /// A label for vertices.
+    type VertexLabel = usize;
+
+    // Constructors
+
+    /// Constructs a graph on `nv` vertices with no edges.
+    fn empty(nv: usize) -> Self {
+        Self {
+            order: nv,
+            size: 0,
+            out_storage: S::empty(nv),
+            in_storage: S::empty(nv),
+        }
+    }
+
+    // Attributes
+
+    /// Returns the number of vertices (not labels) in a graph.
+    fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Returns the number of arcs in a graph.
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of vertex labels in a graph.
+    fn num_v_labels(&self) -> usize {
+        self.out_storage.num_v_labels()
+    }
+
+    // Vertex Modifiers
+
+    /// Add a vertex to the graph and return its label.
+    fn add_vertex(&mut self) -> Self::VertexLabel {
+        self.order += 1;
+        self.in_storage.add_vertex();
+        self.out_storage.add_vertex()
+    }
+
+    fn add_labeled_vertex(&mut self, label: Self::VertexLabel) -> bool {
+        if label < self.out_storage.num_v_labels() {
+            false
+        } else {
+            self.add_vertices(label - self.out_storage.num_v_labels() + 1);
+            true
+        }
+    }
+
+    /// Add `count` vertices to the graph.
+    fn add_vertices(&mut self, count: usize) {
+        self.order += count;
+        self.out_storage.add_vertices(count);
+        self.in_storage.add_vertices(count);
+    }
+
+    // Edge Modifiers
+
+    /// Add the arc `(u, v)` to the graph and return `true` if it is successful.
+    fn add_edge(&mut self, u: Self::VertexLabel, v: Self::VertexLabel) -> bool {
+        if self.out_storage.has_edge(u, v) {
+            false
+        } else {
+            unsafe {
+                self.add_edge_unchecked(u, v);
+            }
+            true
+        }
+    }
+
+    /// Remove the arc `(u, v)` and return `true` if it is successful.
+    fn rem_edge(&mut self, u: Self::VertexLabel, v: Self::VertexLabel) -> bool {
+        if self.out_storage.has_edge(u, v) {
+            self.out_storage.rem_edge(u, v);
+            self.in_storage.rem_edge(v, u);
+            self.size -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // Accessors
+
+    /// Return true if and only if the graph contains the specified vertex label.
+    fn has_vertex(&self, label: Self::VertexLabel) -> bool {
+        self.out_storage.has_vertex(label)
+    }
+
+    /// Return true if and only if the graph contains the arc `(u, v)`.
+    fn has_edge(&self, u: Self::VertexLabel, v: Self::VertexLabel) -> bool {
+        self.out_storage.has_edge(u, v)
+    }
+
+    // Basic Iterators
+
+    /// Iterate over vertices by label.
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = Self::VertexLabel> + 'a {
+        self.out_storage.vertex_iterator()
+    }
+
+    /// Iterate over arcs by label.
+    fn edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel)> + 'a {
+        self.out_storage.edge_iterator()
+    }
+
+    /// Iterate over out-neighbors of `vertex` by label.
+    fn neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a> {
+        self.out_neighbor_iterator(vertex)
+    }
+}
+
+impl<S: Storage> DirectedGraph for DiGraph<S> {
+    /// Iterate over out-neighbors of `vertex` by label.
+    fn out_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a> {
+        if self.out_storage.has_vertex(vertex) {
+            Some(self.out_storage.neighbor_iterator(vertex))
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over in-neighbors of `vertex` by label.
+    fn in_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a> {
+        if self.in_storage.has_vertex(vertex) {
+            Some(self.in_storage.neighbor_iterator(vertex))
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: Storage> FastGraph for DiGraph<S> {
+    unsafe fn add_edge_unchecked(&mut self, u: usize, v: usize) {
+        self.out_storage.add_edge(u, v);
+        self.in_storage.add_edge(v, u);
+        self.size += 1;
+    }
+}
+
+impl<S: WeightedStorage> WeightedGraph for DiGraph<S> {
+    type Weight = S::Weight;
+
+    /// Add the arc `(u, v)` with the specified weight and return `true` if it is successful.
+    fn add_weighted_edge(&mut self, u: usize, v: usize, weight: S::Weight) -> bool {
+        if self.out_storage.has_edge(u, v) {
+            false
+        } else {
+            unsafe {
+                self.out_storage.add_weighted_edge(u, v, weight);
+                self.in_storage.add_weighted_edge(v, u, weight);
+            }
+            self.size += 1;
+            true
+        }
+    }
+
+    /// Return the weight of the arc `(u, v)`, or `None` if the graph does not contain it.
+    fn edge_weight(&self, u: usize, v: usize) -> Option<S::Weight> {
+        self.out_storage.edge_weight(u, v)
+    }
+
+    /// Iterate over arcs by label, along with their weights.
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (usize, usize, S::Weight)> + 'a {
+        self.out_storage.weighted_edge_iterator()
+    }
+
+    /// Iterate over out-neighbors of `vertex` by label, along with the weight of the connecting arc.
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Option<impl Iterator<Item = (usize, S::Weight)> + 'a> {
+        if self.out_storage.has_vertex(vertex) {
+            Some(self.out_storage.weighted_neighbor_iterator(vertex))
+        } else {
+            None
+        }
+    }
+}
+
+impl<S: WeightedStorage> WeightedDirectedGraph for DiGraph<S> {
+    /// Iterate over in-neighbors of `vertex` by label, along with the weight of the connecting arc.
+    fn weighted_in_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Option<impl Iterator<Item = (usize, S::Weight)> + 'a> {
+        if self.in_storage.has_vertex(vertex) {
+            Some(self.in_storage.weighted_neighbor_iterator(vertex))
+        } else {
+            None
+        }
+    }
+}
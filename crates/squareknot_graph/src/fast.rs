@@ -2,5 +2,10 @@ use crate::AbstractGraph;
 
 /// Adds some performance focused methods
 pub trait FastGraph: AbstractGraph<VertexLabel = usize> {
+    /// Add an edge without checking whether it is already present.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the edge `(u, v)` is not already present and that both labels are in the graph.
     unsafe fn add_edge_unchecked(&mut self, u: usize, v: usize);
 }
@@ -1,6 +1,6 @@
 use std::hash::Hash;
 
-use crate::GraphView;
+use crate::{GraphView, TransposeView};
 
 /// Abstract trait for labeled graphs
 ///
@@ -78,7 +78,41 @@ pub trait AbstractGraph: Sized {
         vertex: Self::VertexLabel,
     ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a>;
 
-    fn view(&self) -> GraphView<Self> {
+    fn view(&self) -> GraphView<'_, Self> {
         GraphView::build(self)
     }
 }
+
+/// Abstract trait for labeled directed graphs
+///
+/// For a directed graph, [`AbstractGraph::neighbor_iterator`] iterates over out-neighbors,
+/// and [`AbstractGraph::edge_iterator`] yields each arc `(from, to)` exactly once.
+pub trait DirectedGraph: AbstractGraph {
+    /// Iterate over out-neighbors of `vertex` by label.
+    fn out_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a>;
+
+    /// Iterate over in-neighbors of `vertex` by label.
+    fn in_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a>;
+
+    /// Returns the number of arcs leaving `vertex`, or `None` if `vertex` is not in the graph.
+    fn out_degree(&self, vertex: Self::VertexLabel) -> Option<usize> {
+        self.out_neighbor_iterator(vertex)
+            .map(|neigh| neigh.count())
+    }
+
+    /// Returns the number of arcs entering `vertex`, or `None` if `vertex` is not in the graph.
+    fn in_degree(&self, vertex: Self::VertexLabel) -> Option<usize> {
+        self.in_neighbor_iterator(vertex).map(|neigh| neigh.count())
+    }
+
+    /// Creates a view of the graph with every arc reversed.
+    fn transpose_view(&self) -> TransposeView<'_, Self> {
+        TransposeView::build(self)
+    }
+}
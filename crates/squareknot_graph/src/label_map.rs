@@ -0,0 +1,54 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A bijection between the `usize` labels of a materialized graph and the labels of the view it was built from.
+/// See [`crate::ViewCombinator::materialize`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabelMap<L: Copy + Hash + Eq> {
+    labels: Vec<L>,
+    indices: HashMap<L, usize>,
+}
+
+impl<L: Copy + Hash + Eq> LabelMap<L> {
+    /// Assigns consecutive indices to `labels` in order, skipping repeats.
+    pub fn from_labels<I: IntoIterator<Item = L>>(labels: I) -> Self {
+        let mut map = Self {
+            labels: vec![],
+            indices: HashMap::new(),
+        };
+        for label in labels {
+            map.insert(label);
+        }
+        map
+    }
+
+    /// Returns the index of `label`, assigning the next index if it is new.
+    pub fn insert(&mut self, label: L) -> usize {
+        *self.indices.entry(label).or_insert_with(|| {
+            self.labels.push(label);
+            self.labels.len() - 1
+        })
+    }
+
+    /// Returns the original label of the vertex `index`.
+    pub fn label(&self, index: usize) -> Option<L> {
+        self.labels.get(index).copied()
+    }
+
+    /// Returns the index of the vertex with the original label `label`.
+    pub fn index(&self, label: &L) -> Option<usize> {
+        self.indices.get(label).copied()
+    }
+
+    /// Lists the original labels, so that `labels()[i]` is the label of index `i`.
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
@@ -2,16 +2,43 @@
 //! Additional graph and storage types may be defined outside of this crate.
 
 pub mod combinators;
+pub mod directed;
 pub mod fast;
 pub mod graph;
+pub mod label_map;
 pub mod storage;
 pub mod undirected;
+pub mod weighted;
 
 pub use combinators::*;
+pub use directed::*;
 pub use fast::*;
 pub use graph::*;
+pub use label_map::*;
 pub use storage::*;
 pub use undirected::*;
+pub use weighted::*;
 
 /// An alias for an undirected graph with an adjacency list.
 pub type SimpleGraph = UnGraph<AdjacencyList>;
+
+/// An alias for a directed graph with an adjacency list.
+pub type SimpleDiGraph = DiGraph<AdjacencyList>;
+
+/// An alias for an undirected graph with compressed sparse row storage.
+pub type CsrGraph<I = usize> = UnGraph<Csr<I>>;
+
+/// An alias for a directed graph with compressed sparse row storage.
+pub type CsrDiGraph<I = usize> = DiGraph<Csr<I>>;
+
+/// An alias for an undirected graph with a bit-packed adjacency matrix.
+pub type DenseGraph = UnGraph<BitMatrix>;
+
+/// An alias for a directed graph with a bit-packed adjacency matrix.
+pub type DenseDiGraph = DiGraph<BitMatrix>;
+
+/// An alias for an undirected graph with a weighted adjacency list.
+pub type SimpleWeightedGraph<W> = UnGraph<WeightedAdjacencyList<W>>;
+
+/// An alias for a directed graph with a weighted adjacency list.
+pub type SimpleWeightedDiGraph<W> = DiGraph<WeightedAdjacencyList<W>>;
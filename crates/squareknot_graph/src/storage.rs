@@ -6,6 +6,22 @@ pub mod adjacency_matrix;
 #[cfg(feature = "nalgebra")]
 pub use adjacency_matrix::*;
 
+pub mod bit_matrix;
+pub use bit_matrix::*;
+
+pub mod csr;
+pub use csr::*;
+
+pub mod weighted_adjacency_list;
+pub use weighted_adjacency_list::*;
+
+#[cfg(feature = "nalgebra")]
+pub mod weighted_adjacency_matrix;
+#[cfg(feature = "nalgebra")]
+pub use weighted_adjacency_matrix::*;
+
+use crate::Weight;
+
 /// A trait which, for the most part, mirrors [`crate::AbstractGraph`]
 /// This provides an extra layer of abstraction, allowing the reuse of storage code for both directed and undirected graphs.
 /// This also allows graphs using this trait to be backend independent, allowing them to be reusable components as well.
@@ -17,6 +33,19 @@ pub trait Storage: Sized {
     /// Construct storage for a graph on `nv` vertices with no edges.
     fn empty(nv: usize) -> Self;
 
+    /// Construct storage holding every edge of `other`, e.g. to change the backend of a graph.
+    /// Weights are not copied, see [`WeightedStorage::from_weighted_storage`].
+    /// Implementations may override this when they can be built faster than by repeated [`Storage::add_edge`].
+    fn from_storage<S: Storage>(other: &S) -> Self {
+        let mut storage = Self::empty(other.num_v_labels());
+        for (from, to) in other.edge_iterator() {
+            if !storage.has_edge(from, to) {
+                unsafe { storage.add_edge(from, to) }
+            }
+        }
+        storage
+    }
+
     // Attributes
 
     /// Return the number of vertex labels in storage.
@@ -37,6 +66,10 @@ pub trait Storage: Sized {
     // Edge Modifiers
 
     /// Add an edge. This is unsafe since it can produce multi-edges.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the edge `(from, to)` is not already present and that both labels are in storage.
     unsafe fn add_edge(&mut self, from: usize, to: usize);
 
     /// Remove an edge based on its label.
@@ -65,3 +98,55 @@ pub trait Storage: Sized {
     /// Iterate over neighbors of `vertex` by label.
     fn neighbor_iterator<'a>(&'a self, vertex: usize) -> impl Iterator<Item = usize> + 'a;
 }
+
+/// A [`Storage`] which holds nothing but adjacency, so that graphs may be converted into it without losing data.
+/// Weighted storage must not implement this, since a generic conversion would give every edge [`Weight::one`].
+pub trait UnweightedStorage: Storage {}
+
+/// An extension of [`Storage`] which associates a weight with every edge.
+/// Edges added through [`Storage::add_edge`] are given the weight [`Weight::one`].
+pub trait WeightedStorage: Storage {
+    /// The type of edge weights.
+    type Weight: Weight;
+
+    // Constructors
+
+    /// Construct storage holding every edge of `other` along with its weight.
+    fn from_weighted_storage<S: WeightedStorage<Weight = Self::Weight>>(other: &S) -> Self {
+        let mut storage = Self::empty(other.num_v_labels());
+        for (from, to, weight) in other.weighted_edge_iterator() {
+            if !storage.has_edge(from, to) {
+                unsafe { storage.add_weighted_edge(from, to, weight) }
+            }
+        }
+        storage
+    }
+
+    // Edge Modifiers
+
+    /// Add a weighted edge. This is unsafe since it can produce multi-edges.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the edge `(from, to)` is not already present and that both labels are in storage.
+    unsafe fn add_weighted_edge(&mut self, from: usize, to: usize, weight: Self::Weight);
+
+    // Accessors
+
+    /// Return the weight of the specified edge, or `None` if it is not present.
+    fn edge_weight(&self, from: usize, to: usize) -> Option<Self::Weight>;
+
+    // Basic Iterators
+
+    /// Iterate over edges by label, along with their weights.
+    /// This iterator may have duplicates.
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (usize, usize, Self::Weight)> + 'a;
+
+    /// Iterate over neighbors of `vertex` by label, along with the weight of the connecting edge.
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> impl Iterator<Item = (usize, Self::Weight)> + 'a;
+}
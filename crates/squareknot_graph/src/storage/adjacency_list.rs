@@ -1,4 +1,4 @@
-use super::Storage;
+use super::{Storage, UnweightedStorage};
 
 #[derive(Clone)]
 struct AdjacencyNode {
@@ -9,6 +9,8 @@ pub struct AdjacencyList {
     list: Vec<AdjacencyNode>,
 }
 
+impl UnweightedStorage for AdjacencyList {}
+
 impl Storage for AdjacencyList {
     // Constructors
 
@@ -53,7 +55,7 @@ impl Storage for AdjacencyList {
     /// Remove an edge based on its label.
     fn rem_edge(&mut self, from: usize, to: usize) {
         if from < self.list.len() && to < self.list.len() {
-            if let Some(&i) = self.list[from].neighbors.iter().find(|&&x| x == to) {
+            if let Some(i) = self.list[from].neighbors.iter().position(|&x| x == to) {
                 self.list[from].neighbors.remove(i);
             }
         }
@@ -89,8 +91,7 @@ impl Storage for AdjacencyList {
         self.list
             .iter()
             .enumerate()
-            .map(|(u, x)| x.neighbors.iter().map(move |&v| (u, v)))
-            .flatten()
+            .flat_map(|(u, x)| x.neighbors.iter().map(move |&v| (u, v)))
     }
 
     /// Iterate over neighbors of `vertex` by label.
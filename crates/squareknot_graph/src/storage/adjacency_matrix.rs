@@ -1,11 +1,13 @@
 use nalgebra::DMatrix;
 
-use super::Storage;
+use super::{Storage, UnweightedStorage};
 
 pub struct AdjacencyMatrix {
     matrix: DMatrix<u8>,
 }
 
+impl UnweightedStorage for AdjacencyMatrix {}
+
 impl Storage for AdjacencyMatrix {
     // Constructors
 
@@ -78,13 +80,11 @@ impl Storage for AdjacencyMatrix {
     /// Iterate over edges by label.
     fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
         let nv = self.matrix.nrows();
-        (0..nv)
-            .map(move |u| {
-                (0..nv)
-                    .map(move |v| (u, v))
-                    .filter(|&coord| self.matrix[coord] == 1)
-            })
-            .flatten()
+        (0..nv).flat_map(move |u| {
+            (0..nv)
+                .map(move |v| (u, v))
+                .filter(|&coord| self.matrix[coord] == 1)
+        })
     }
 
     /// Iterate over neighbors of `vertex` by label.
@@ -0,0 +1,231 @@
+use super::{Storage, UnweightedStorage};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Iterates over the positions of the set bits in a sequence of words, in increasing order.
+#[derive(Clone, Debug)]
+pub struct BitIter<W: Iterator<Item = u64>> {
+    words: W,
+    current: u64,
+    base: usize,
+}
+
+impl<W: Iterator<Item = u64>> BitIter<W> {
+    pub fn new<T: IntoIterator<IntoIter = W>>(words: T) -> Self {
+        Self {
+            words: words.into_iter(),
+            current: 0,
+            // Wraps to zero when the first word is loaded
+            base: 0usize.wrapping_sub(WORD_BITS),
+        }
+    }
+}
+
+impl<W: Iterator<Item = u64>> Iterator for BitIter<W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.current = self.words.next()?;
+            self.base = self.base.wrapping_add(WORD_BITS);
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.base + bit)
+    }
+}
+
+/// An adjacency matrix packed into `u64` words, with one bit per ordered pair of vertices.
+///
+/// Neighbors are found by scanning for set bits a word at a time,
+/// and whole neighborhoods may be intersected or combined with word-parallel operations.
+/// Each row is padded to a whole number of words, and bits past the last vertex are always zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitMatrix {
+    nv: usize,
+    /// The number of words in each row, which is at least `nv / 64` rounded up.
+    stride: usize,
+    words: Vec<u64>,
+}
+
+impl BitMatrix {
+    /// Returns the words of the row of `vertex`.
+    pub fn row(&self, vertex: usize) -> &[u64] {
+        &self.words[vertex * self.stride..(vertex + 1) * self.stride]
+    }
+
+    fn row_mut(&mut self, vertex: usize) -> &mut [u64] {
+        &mut self.words[vertex * self.stride..(vertex + 1) * self.stride]
+    }
+
+    /// Returns the number of neighbors of `vertex`.
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.row(vertex)
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterate over the vertices adjacent to both `u` and `v`.
+    pub fn common_neighbors<'a>(&'a self, u: usize, v: usize) -> impl Iterator<Item = usize> + 'a {
+        BitIter::new(self.row(u).iter().zip(self.row(v)).map(|(a, b)| a & b))
+    }
+
+    /// Returns the number of vertices adjacent to both `u` and `v`.
+    pub fn common_degree(&self, u: usize, v: usize) -> usize {
+        self.row(u)
+            .iter()
+            .zip(self.row(v))
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterate over the vertices adjacent to `u` or `v`.
+    pub fn neighborhood_union<'a>(
+        &'a self,
+        u: usize,
+        v: usize,
+    ) -> impl Iterator<Item = usize> + 'a {
+        BitIter::new(self.row(u).iter().zip(self.row(v)).map(|(a, b)| a | b))
+    }
+
+    /// Restricts the vertex set `set` to the neighbors of `vertex`.
+    /// `set` is a bitset with at least as many words as a row, such as a copy of [`BitMatrix::row`].
+    pub fn intersect_into(&self, vertex: usize, set: &mut [u64]) {
+        for (s, w) in set.iter_mut().zip(self.row(vertex)) {
+            *s &= w;
+        }
+    }
+
+    /// Adds the neighbors of `vertex` to the vertex set `set`.
+    /// `set` is a bitset with at least as many words as a row, such as a copy of [`BitMatrix::row`].
+    pub fn union_into(&self, vertex: usize, set: &mut [u64]) {
+        for (s, w) in set.iter_mut().zip(self.row(vertex)) {
+            *s |= w;
+        }
+    }
+
+    /// Counts the triangles of an undirected graph, i.e. of storage holding every edge in both directions.
+    pub fn triangle_count(&self) -> usize {
+        let mut count = 0;
+        for u in 0..self.nv {
+            // Only count each triangle from its smallest vertex, through its middle vertex
+            for v in BitIter::new(self.row(u).iter().copied()).filter(|&v| v > u) {
+                let first = (v + 1) / WORD_BITS;
+                let mask = !0u64 << ((v + 1) % WORD_BITS);
+                count += self.row(u)[first..]
+                    .iter()
+                    .zip(&self.row(v)[first..])
+                    .enumerate()
+                    .map(|(i, (a, b))| {
+                        let word = a & b;
+                        (if i == 0 { word & mask } else { word }).count_ones() as usize
+                    })
+                    .sum::<usize>();
+            }
+        }
+        count
+    }
+
+    /// Grows the rows to hold at least `nv` columns, doubling the stride to amortize the copying.
+    fn reserve_columns(&mut self, nv: usize) {
+        let needed = nv.div_ceil(WORD_BITS);
+        if needed <= self.stride {
+            return;
+        }
+        let stride = needed.max(2 * self.stride);
+        let mut words = vec![0; self.nv * stride];
+        for v in 0..self.nv {
+            words[v * stride..v * stride + self.stride].copy_from_slice(self.row(v));
+        }
+        self.stride = stride;
+        self.words = words;
+    }
+}
+
+impl UnweightedStorage for BitMatrix {}
+
+impl Storage for BitMatrix {
+    // Constructors
+
+    /// Construct storage for a graph on `nv` vertices with no edges.
+    fn empty(nv: usize) -> Self {
+        let stride = nv.div_ceil(WORD_BITS);
+        Self {
+            nv,
+            stride,
+            words: vec![0; nv * stride],
+        }
+    }
+
+    // Attributes
+
+    /// Return the number of vertex labels in storage.
+    fn num_v_labels(&self) -> usize {
+        self.nv
+    }
+
+    // Vertex modifiers
+
+    /// Add a vertex to storage and return its label.
+    fn add_vertex(&mut self) -> usize {
+        self.add_vertices(1);
+        self.nv - 1
+    }
+
+    /// Add `count` vertices to storage.
+    fn add_vertices(&mut self, count: usize) {
+        self.reserve_columns(self.nv + count);
+        self.nv += count;
+        self.words.resize(self.nv * self.stride, 0);
+    }
+
+    // Edge Modifiers
+
+    /// Add an edge. This is unchecked.
+    unsafe fn add_edge(&mut self, from: usize, to: usize) {
+        self.row_mut(from)[to / WORD_BITS] |= 1 << (to % WORD_BITS);
+    }
+
+    /// Remove an edge based on its label.
+    fn rem_edge(&mut self, from: usize, to: usize) {
+        if from < self.nv && to < self.nv {
+            self.row_mut(from)[to / WORD_BITS] &= !(1 << (to % WORD_BITS));
+        }
+    }
+
+    /// Remove an undirected edge.
+    fn rem_undirected_edge(&mut self, u: usize, v: usize) {
+        self.rem_edge(u, v);
+        self.rem_edge(v, u);
+    }
+
+    // Accessors
+
+    /// Return true if and only if the graph contains the specified vertex label.
+    fn has_vertex(&self, label: usize) -> bool {
+        label < self.nv
+    }
+
+    /// Return true if and only if the graph contains the specified edge label.
+    fn has_edge(&self, from: usize, to: usize) -> bool {
+        to < self.nv && self.row(from)[to / WORD_BITS] & (1 << (to % WORD_BITS)) != 0
+    }
+
+    // Basic Iterators
+
+    /// Iterate over vertices by label.
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        0..self.nv
+    }
+
+    /// Iterate over edges by label.
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        (0..self.nv).flat_map(move |u| self.neighbor_iterator(u).map(move |v| (u, v)))
+    }
+
+    /// Iterate over neighbors of `vertex` by label.
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> impl Iterator<Item = usize> + 'a {
+        BitIter::new(self.row(vertex).iter().copied())
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt::Debug;
+
+use super::{Storage, UnweightedStorage};
+
+/// An integer type which may be used to store vertex labels in a [`Csr`].
+pub trait CsrIndex: Copy + Ord + Debug + Send + Sync + 'static {
+    /// Converts a label into an index, panicking if it does not fit.
+    fn from_label(label: usize) -> Self;
+
+    /// Converts an index back into a label.
+    fn label(self) -> usize;
+}
+
+impl CsrIndex for usize {
+    fn from_label(label: usize) -> Self {
+        label
+    }
+
+    fn label(self) -> usize {
+        self
+    }
+}
+
+impl CsrIndex for u32 {
+    fn from_label(label: usize) -> Self {
+        u32::try_from(label).expect("vertex label does not fit in a u32 index")
+    }
+
+    fn label(self) -> usize {
+        self as usize
+    }
+}
+
+/// Compressed sparse row storage.
+///
+/// The neighbors of every vertex are stored contiguously and in increasing order in a single array,
+/// so iterating over neighbors is cache friendly and [`Storage::has_edge`] is a binary search.
+/// Using `u32` indices halves the memory used by the neighbor array.
+///
+/// This storage is intended to be built once with a [`CsrBuilder`].
+/// Mutation is supported, but adding or removing an edge shifts every later entry and costs `O(m)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Csr<I: CsrIndex = usize> {
+    /// The neighbors of `v` are `targets[offsets[v]..offsets[v + 1]]`.
+    offsets: Vec<usize>,
+    targets: Vec<I>,
+}
+
+impl<I: CsrIndex> Csr<I> {
+    /// Returns the number of stored entries, which is the number of arcs of a directed graph
+    /// and twice the number of edges of an undirected graph.
+    pub fn num_entries(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns the number of neighbors of `vertex`.
+    pub fn degree(&self, vertex: usize) -> usize {
+        self.offsets[vertex + 1] - self.offsets[vertex]
+    }
+
+    /// Returns the sorted neighbors of `vertex`.
+    pub fn neighbors(&self, vertex: usize) -> &[I] {
+        &self.targets[self.offsets[vertex]..self.offsets[vertex + 1]]
+    }
+
+    /// Constructs the storage with every entry reversed, e.g. in-neighbors from out-neighbors.
+    pub fn transpose(&self) -> Self {
+        let mut builder = CsrBuilder::new(self.num_v_labels());
+        builder.extend(self.edge_iterator().map(|(u, v)| (v, u)));
+        builder.build()
+    }
+}
+
+impl<I: CsrIndex> UnweightedStorage for Csr<I> {}
+
+impl<I: CsrIndex> Storage for Csr<I> {
+    // Constructors
+
+    /// Construct storage for a graph on `nv` vertices with no edges.
+    fn empty(nv: usize) -> Self {
+        Self {
+            offsets: vec![0; nv + 1],
+            targets: vec![],
+        }
+    }
+
+    /// Construct storage holding every edge of `other` with a [`CsrBuilder`].
+    fn from_storage<S: Storage>(other: &S) -> Self {
+        CsrBuilder::from_storage(other).build()
+    }
+
+    // Attributes
+
+    /// Return the number of vertex labels in storage.
+    fn num_v_labels(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    // Vertex modifiers
+
+    /// Add a vertex to storage and return its label.
+    fn add_vertex(&mut self) -> usize {
+        self.offsets.push(self.targets.len());
+        self.offsets.len() - 2
+    }
+
+    /// Add `count` vertices to storage.
+    fn add_vertices(&mut self, count: usize) {
+        let nv = self.offsets.len();
+        self.offsets.resize(nv + count, self.targets.len());
+    }
+
+    // Edge Modifiers
+
+    /// Add an edge. This is unchecked, and shifts every later entry.
+    unsafe fn add_edge(&mut self, from: usize, to: usize) {
+        let to = I::from_label(to);
+        let position = match self.neighbors(from).binary_search(&to) {
+            Ok(i) | Err(i) => self.offsets[from] + i,
+        };
+        self.targets.insert(position, to);
+        for offset in &mut self.offsets[from + 1..] {
+            *offset += 1;
+        }
+    }
+
+    /// Remove an edge based on its label.
+    fn rem_edge(&mut self, from: usize, to: usize) {
+        if from < self.num_v_labels() && to < self.num_v_labels() {
+            if let Ok(i) = self.neighbors(from).binary_search(&I::from_label(to)) {
+                self.targets.remove(self.offsets[from] + i);
+                for offset in &mut self.offsets[from + 1..] {
+                    *offset -= 1;
+                }
+            }
+        }
+    }
+
+    /// Remove an undirected edge.
+    fn rem_undirected_edge(&mut self, u: usize, v: usize) {
+        self.rem_edge(u, v);
+        self.rem_edge(v, u);
+    }
+
+    // Accessors
+
+    /// Return true if and only if the graph contains the specified vertex label.
+    fn has_vertex(&self, label: usize) -> bool {
+        label < self.num_v_labels()
+    }
+
+    /// Return true if and only if the graph contains the specified edge label.
+    fn has_edge(&self, from: usize, to: usize) -> bool {
+        to < self.num_v_labels()
+            && self
+                .neighbors(from)
+                .binary_search(&I::from_label(to))
+                .is_ok()
+    }
+
+    // Basic Iterators
+
+    /// Iterate over vertices by label.
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        0..self.num_v_labels()
+    }
+
+    /// Iterate over edges by label.
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.vertex_iterator()
+            .flat_map(|u| self.neighbor_iterator(u).map(move |v| (u, v)))
+    }
+
+    /// Iterate over neighbors of `vertex` by label.
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> impl Iterator<Item = usize> + 'a {
+        self.neighbors(vertex).iter().map(|&v| v.label())
+    }
+}
+
+/// Collects entries for a [`Csr`], sorting them into rows with a counting sort when built.
+///
+/// Entries are directed, so an undirected graph must be given both `(u, v)` and `(v, u)`,
+/// or built with [`CsrBuilder::symmetric`]. Repeated entries are removed.
+#[derive(Clone, Debug)]
+pub struct CsrBuilder<I: CsrIndex = usize> {
+    nv: usize,
+    entries: Vec<(I, I)>,
+    symmetric: bool,
+}
+
+impl<I: CsrIndex> CsrBuilder<I> {
+    /// Creates a builder for storage on `nv` vertices.
+    pub fn new(nv: usize) -> Self {
+        Self {
+            nv,
+            entries: vec![],
+            symmetric: false,
+        }
+    }
+
+    /// Creates a builder holding every entry of `storage`.
+    pub fn from_storage<S: Storage>(storage: &S) -> Self {
+        let mut builder = Self::new(storage.num_v_labels());
+        builder.extend(storage.edge_iterator());
+        builder
+    }
+
+    /// Adds the reverse of every entry when built, as an undirected graph requires.
+    pub fn symmetric(mut self) -> Self {
+        self.symmetric = true;
+        self
+    }
+
+    /// Adds the entry `(from, to)`.
+    pub fn add_edge(&mut self, from: usize, to: usize) -> &mut Self {
+        assert!(
+            from < self.nv && to < self.nv,
+            "edge ({from}, {to}) is out of bounds for {} vertices",
+            self.nv
+        );
+        self.entries.push((I::from_label(from), I::from_label(to)));
+        self
+    }
+
+    pub fn build(mut self) -> Csr<I> {
+        if self.symmetric {
+            let reversed = self
+                .entries
+                .iter()
+                .map(|&(u, v)| (v, u))
+                .collect::<Vec<_>>();
+            self.entries.extend(reversed);
+        }
+
+        let mut offsets = vec![0; self.nv + 1];
+        for &(u, _) in &self.entries {
+            offsets[u.label() + 1] += 1;
+        }
+        for v in 0..self.nv {
+            offsets[v + 1] += offsets[v];
+        }
+
+        let mut next = offsets.clone();
+        let mut targets = vec![I::from_label(0); self.entries.len()];
+        for &(u, v) in &self.entries {
+            targets[next[u.label()]] = v;
+            next[u.label()] += 1;
+        }
+
+        // Sort and deduplicate each row, compacting the rows towards the front
+        let mut len = 0;
+        for v in 0..self.nv {
+            let (start, end) = (offsets[v], offsets[v + 1]);
+            offsets[v] = len;
+            targets[start..end].sort_unstable();
+            for i in start..end {
+                if len == offsets[v] || targets[len - 1] != targets[i] {
+                    targets[len] = targets[i];
+                    len += 1;
+                }
+            }
+        }
+        offsets[self.nv] = len;
+        targets.truncate(len);
+
+        Csr { offsets, targets }
+    }
+}
+
+impl<I: CsrIndex> Extend<(usize, usize)> for CsrBuilder<I> {
+    fn extend<T: IntoIterator<Item = (usize, usize)>>(&mut self, iter: T) {
+        for (u, v) in iter {
+            self.add_edge(u, v);
+        }
+    }
+}
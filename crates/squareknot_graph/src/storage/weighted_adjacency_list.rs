@@ -0,0 +1,124 @@
+use super::{Storage, WeightedStorage};
+use crate::Weight;
+
+#[derive(Clone)]
+struct WeightedAdjacencyNode<W: Weight> {
+    neighbors: Vec<(usize, W)>,
+}
+
+pub struct WeightedAdjacencyList<W: Weight> {
+    list: Vec<WeightedAdjacencyNode<W>>,
+}
+
+impl<W: Weight> Storage for WeightedAdjacencyList<W> {
+    // Constructors
+
+    /// Construct storage for a graph on `nv` vertices with no edges.
+    fn empty(nv: usize) -> Self {
+        Self {
+            list: (0..nv)
+                .map(|_x| WeightedAdjacencyNode { neighbors: vec![] })
+                .collect(),
+        }
+    }
+
+    // Attributes
+
+    /// Return the number of vertex labels in storage.
+    fn num_v_labels(&self) -> usize {
+        self.list.len()
+    }
+
+    // Vertex modifiers
+
+    /// Add a vertex to storage and return its label.
+    fn add_vertex(&mut self) -> usize {
+        self.list.push(WeightedAdjacencyNode { neighbors: vec![] });
+        self.list.len() - 1
+    }
+
+    // Edge Modifiers
+
+    /// Add an edge with weight [`Weight::one`]. This is unchecked.
+    unsafe fn add_edge(&mut self, from: usize, to: usize) {
+        self.add_weighted_edge(from, to, W::one())
+    }
+
+    /// Remove an edge based on its label.
+    fn rem_edge(&mut self, from: usize, to: usize) {
+        if from < self.list.len() && to < self.list.len() {
+            if let Some(i) = self.list[from].neighbors.iter().position(|&(x, _)| x == to) {
+                self.list[from].neighbors.remove(i);
+            }
+        }
+    }
+
+    /// Remove an undirected edge.
+    fn rem_undirected_edge(&mut self, u: usize, v: usize) {
+        self.rem_edge(u, v);
+        self.rem_edge(v, u);
+    }
+
+    // Accessors
+
+    /// Return true if and only if the graph contains the specified vertex label.
+    fn has_vertex(&self, label: usize) -> bool {
+        label < self.list.len()
+    }
+
+    /// Return true if and only if the graph contains the specified edge label.
+    fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.list[from].neighbors.iter().any(|&(x, _)| x == to)
+    }
+
+    // Basic Iterators
+
+    /// Iterate over vertices by label.
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        0..self.list.len()
+    }
+
+    /// Iterate over edges by label.
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.weighted_edge_iterator().map(|(u, v, _)| (u, v))
+    }
+
+    /// Iterate over neighbors of `vertex` by label.
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> impl Iterator<Item = usize> + 'a {
+        self.list[vertex].neighbors.iter().map(|&(v, _)| v)
+    }
+}
+
+impl<W: Weight> WeightedStorage for WeightedAdjacencyList<W> {
+    type Weight = W;
+
+    /// Add a weighted edge. This is unchecked.
+    unsafe fn add_weighted_edge(&mut self, from: usize, to: usize, weight: W) {
+        self.list[from].neighbors.push((to, weight))
+    }
+
+    /// Return the weight of an edge if it is present.
+    fn edge_weight(&self, from: usize, to: usize) -> Option<W> {
+        self.list[from]
+            .neighbors
+            .iter()
+            .find(|&&(x, _)| x == to)
+            .map(|&(_, w)| w)
+    }
+
+    /// Iterate over edges by label, along with their weights.
+    fn weighted_edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize, W)> + 'a {
+        self.list
+            .iter()
+            .enumerate()
+            .flat_map(|(u, x)| x.neighbors.iter().map(move |&(v, w)| (u, v, w)))
+    }
+
+    /// Iterate over neighbors of `vertex` by label, along with the weight of the connecting edge.
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> impl Iterator<Item = (usize, W)> + 'a {
+        self.list[vertex].neighbors.iter().cloned()
+    }
+}
@@ -0,0 +1,118 @@
+use nalgebra::DMatrix;
+
+use super::{Storage, WeightedStorage};
+use crate::Weight;
+
+pub struct WeightedAdjacencyMatrix<W: Weight> {
+    matrix: DMatrix<Option<W>>,
+}
+
+impl<W: Weight> Storage for WeightedAdjacencyMatrix<W> {
+    // Constructors
+
+    /// Construct storage for a graph on `nv` vertices with no edges.
+    fn empty(nv: usize) -> Self {
+        Self {
+            matrix: DMatrix::from_element(nv, nv, None),
+        }
+    }
+
+    // Attributes
+
+    /// Return the number of vertex labels in storage.
+    fn num_v_labels(&self) -> usize {
+        self.matrix.nrows()
+    }
+
+    // Vertex modifiers
+
+    /// Add a vertex to storage and return its label.
+    fn add_vertex(&mut self) -> usize {
+        let nv = self.matrix.nrows();
+        self.matrix.resize_mut(nv + 1, nv + 1, None);
+        nv
+    }
+
+    /// Add `count` vertices to storage.
+    fn add_vertices(&mut self, count: usize) {
+        let nv = self.matrix.nrows();
+        self.matrix.resize_mut(nv + count, nv + count, None);
+    }
+
+    // Edge Modifiers
+
+    /// Add an edge with weight [`Weight::one`]. This is unchecked.
+    unsafe fn add_edge(&mut self, from: usize, to: usize) {
+        self.matrix[(from, to)] = Some(W::one());
+    }
+
+    /// Remove an edge based on its label.
+    fn rem_edge(&mut self, from: usize, to: usize) {
+        self.matrix[(from, to)] = None;
+    }
+
+    /// Remove an undirected edge.
+    fn rem_undirected_edge(&mut self, u: usize, v: usize) {
+        self.matrix[(u, v)] = None;
+        self.matrix[(v, u)] = None;
+    }
+
+    // Accessors
+
+    /// Return true if and only if the graph contains the specified vertex label.
+    fn has_vertex(&self, label: usize) -> bool {
+        label < self.matrix.nrows()
+    }
+
+    /// Return true if and only if the graph contains the specified edge label.
+    fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.matrix[(from, to)].is_some()
+    }
+
+    // Basic Iterators
+
+    /// Iterate over vertices by label.
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        0..self.matrix.nrows()
+    }
+
+    /// Iterate over edges by label.
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.weighted_edge_iterator().map(|(u, v, _)| (u, v))
+    }
+
+    /// Iterate over neighbors of `vertex` by label.
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> impl Iterator<Item = usize> + 'a {
+        self.weighted_neighbor_iterator(vertex).map(|(v, _)| v)
+    }
+}
+
+impl<W: Weight> WeightedStorage for WeightedAdjacencyMatrix<W> {
+    type Weight = W;
+
+    /// Add a weighted edge. This is unchecked.
+    unsafe fn add_weighted_edge(&mut self, from: usize, to: usize, weight: W) {
+        self.matrix[(from, to)] = Some(weight);
+    }
+
+    /// Return the weight of an edge if it is present.
+    fn edge_weight(&self, from: usize, to: usize) -> Option<W> {
+        self.matrix[(from, to)]
+    }
+
+    /// Iterate over edges by label, along with their weights.
+    fn weighted_edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize, W)> + 'a {
+        let nv = self.matrix.nrows();
+        (0..nv)
+            .flat_map(move |u| (0..nv).filter_map(move |v| self.matrix[(u, v)].map(|w| (u, v, w))))
+    }
+
+    /// Iterate over neighbors of `vertex` by label, along with the weight of the connecting edge.
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> impl Iterator<Item = (usize, W)> + 'a {
+        let nv = self.matrix.nrows();
+        (0..nv).filter_map(move |v| self.matrix[(vertex, v)].map(|w| (v, w)))
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{AbstractGraph, FastGraph, Storage};
+use crate::{
+    AbstractGraph, FastGraph, Storage, UnweightedStorage, Weight, WeightedAdjacencyList,
+    WeightedGraph, WeightedStorage,
+};
 
 /// An undirected graph with a generic storage mechanism.
 pub struct UnGraph<S: Storage> {
@@ -7,6 +10,62 @@ pub struct UnGraph<S: Storage> {
     storage: S,
 }
 
+impl<S: Storage> UnGraph<S> {
+    /// Constructs a graph from existing storage, which must hold every edge in both directions.
+    pub fn from_storage(storage: S) -> Self {
+        Self {
+            order: storage.vertex_iterator().count(),
+            size: storage.edge_iterator().filter(|(u, v)| u <= v).count(),
+            storage,
+        }
+    }
+
+    /// Returns the underlying storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+}
+
+impl<S: Storage> UnGraph<S> {
+    /// Rebuilds the graph on new storage. Repeated edges of the source are merged, so the size is recounted.
+    fn convert<T: Storage>(&self, storage: T) -> UnGraph<T> {
+        UnGraph {
+            order: self.order,
+            size: storage.edge_iterator().filter(|(u, v)| u <= v).count(),
+            storage,
+        }
+    }
+}
+
+/// Converts between storage backends, e.g. from an adjacency list to [`crate::Csr`].
+/// Weights of the source are dropped.
+impl<S: Storage, T: UnweightedStorage> From<&UnGraph<S>> for UnGraph<T> {
+    fn from(graph: &UnGraph<S>) -> Self {
+        graph.convert(T::from_storage(&graph.storage))
+    }
+}
+
+/// Converts between weighted storage backends, keeping the weight of every edge.
+impl<W: Weight, S: WeightedStorage<Weight = W>> From<&UnGraph<S>>
+    for UnGraph<WeightedAdjacencyList<W>>
+{
+    fn from(graph: &UnGraph<S>) -> Self {
+        graph.convert(WeightedAdjacencyList::from_weighted_storage(&graph.storage))
+    }
+}
+
+/// Converts between weighted storage backends, keeping the weight of every edge.
+#[cfg(feature = "nalgebra")]
+impl<W: Weight, S: WeightedStorage<Weight = W>> From<&UnGraph<S>>
+    for UnGraph<crate::WeightedAdjacencyMatrix<W>>
+{
+    fn from(graph: &UnGraph<S>) -> Self {
+        graph.convert(crate::WeightedAdjacencyMatrix::from_weighted_storage(
+            &graph.storage,
+        ))
+    }
+}
+
 impl<S: Storage> AbstractGraph for UnGraph<S> {
     /// A label for vertices.
     type VertexLabel = usize;
@@ -74,6 +133,7 @@ impl<S: Storage> AbstractGraph for UnGraph<S> {
                 self.storage.add_edge(u, v);
                 self.storage.add_edge(v, u);
             }
+            self.size += 1;
             true
         }
     }
@@ -128,5 +188,44 @@ impl<S: Storage> FastGraph for UnGraph<S> {
     unsafe fn add_edge_unchecked(&mut self, u: usize, v: usize) {
         self.storage.add_edge(u, v);
         self.storage.add_edge(v, u);
+        self.size += 1;
+    }
+}
+
+impl<S: WeightedStorage> WeightedGraph for UnGraph<S> {
+    type Weight = S::Weight;
+
+    /// Add an edge with the specified weight and return `true` if it is successful.
+    fn add_weighted_edge(&mut self, u: usize, v: usize, weight: S::Weight) -> bool {
+        if self.storage.has_edge(u, v) {
+            false
+        } else {
+            unsafe {
+                self.storage.add_weighted_edge(u, v, weight);
+                self.storage.add_weighted_edge(v, u, weight);
+            }
+            self.size += 1;
+            true
+        }
+    }
+
+    /// Return the weight of the edge `(u, v)`, or `None` if the graph does not contain it.
+    fn edge_weight(&self, u: usize, v: usize) -> Option<S::Weight> {
+        self.storage.edge_weight(u, v)
+    }
+
+    /// Iterate over edges by label, along with their weights.
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (usize, usize, S::Weight)> + 'a {
+        self.storage.weighted_edge_iterator()
+    }
+
+    /// Iterate over neighbors of `vertex` by label, along with the weight of the connecting edge.
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: usize,
+    ) -> Option<impl Iterator<Item = (usize, S::Weight)> + 'a> {
+        Some(self.storage.weighted_neighbor_iterator(vertex))
     }
 }
@@ -0,0 +1,73 @@
+use std::{fmt::Debug, ops::Add};
+
+use crate::{AbstractGraph, DirectedGraph};
+
+/// A type which may be used as an edge weight.
+///
+/// Weights only need to be partially ordered so that floating point numbers may be used.
+/// Custom cost types may implement this trait directly.
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> + Debug + 'static {
+    /// The additive identity, i.e. the cost of an empty path.
+    fn zero() -> Self;
+
+    /// The weight given to edges added without an explicit weight.
+    fn one() -> Self;
+}
+
+macro_rules! impl_weight {
+    ($zero:literal, $one:literal; $($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_weight!(0, 1; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_weight!(0.0, 1.0; f32, f64);
+
+/// Abstract trait for labeled graphs with weighted edges
+///
+/// Edges added through [`AbstractGraph::add_edge`] are given the weight [`Weight::one`].
+pub trait WeightedGraph: AbstractGraph {
+    /// The type of edge weights.
+    type Weight: Weight;
+
+    /// Add an edge with the specified weight and return `true` if it is successful.
+    fn add_weighted_edge(
+        &mut self,
+        u: Self::VertexLabel,
+        v: Self::VertexLabel,
+        weight: Self::Weight,
+    ) -> bool;
+
+    /// Return the weight of the edge `(u, v)`, or `None` if the graph does not contain it.
+    fn edge_weight(&self, u: Self::VertexLabel, v: Self::VertexLabel) -> Option<Self::Weight>;
+
+    /// Iterate over edges by label, along with their weights.
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a;
+
+    /// Iterate over neighbors of `vertex` by label, along with the weight of the connecting edge.
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a>;
+}
+
+/// Abstract trait for labeled directed graphs with weighted edges
+pub trait WeightedDirectedGraph: WeightedGraph + DirectedGraph {
+    /// Iterate over in-neighbors of `vertex` by label, along with the weight of the connecting arc.
+    fn weighted_in_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a>;
+}
@@ -14,6 +14,12 @@ pub use squareknot_metadata as metadata;
 #[cfg(feature = "pathing")]
 pub use squareknot_pathing as pathing;
 
+#[cfg(feature = "constructors")]
+pub use graph_constructors as constructors;
+
+#[cfg(feature = "algebra")]
+pub use squareknot_algebra as algebra;
+
 #[cfg(feature = "planarity")]
 pub use squareknot_planarity as planarity;
 
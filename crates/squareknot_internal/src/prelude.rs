@@ -1,3 +1,7 @@
+#[cfg(feature = "algebra")]
+pub use super::algebra::*;
 pub use super::graph::*;
+#[cfg(feature = "pathing")]
+pub use super::pathing::*;
 #[cfg(feature = "traversal")]
 pub use super::traversal::*;
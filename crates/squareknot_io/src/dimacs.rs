@@ -1,32 +1,199 @@
-use std::marker::PhantomData;
+use std::{io::Write, marker::PhantomData};
 
 use squareknot_graph::{AbstractGraph, FastGraph};
 
-use super::{pair_iter::PairIterator, GraphFormat};
+use super::{
+    check_order, error::Position, pair_iter::PairIterator, tokens::LineTokens, undirected_edges,
+    GraphFormat, GraphFormatError,
+};
 
 /// This is not real DIMACS
 /// This is a modified DIMACS format
+///
+/// The first line holds the number of vertices and edges, and each following line holds a 0-based edge.
 pub struct FakeDimacs<G: AbstractGraph> {
     _p: PhantomData<G>,
 }
 
+/// Checks that a 0-based vertex index refers to one of `nv` vertices.
+fn check_vertex((position, v): (Position, usize), nv: usize) -> Result<usize, GraphFormatError> {
+    if v < nv {
+        Ok(v)
+    } else {
+        Err(GraphFormatError::VertexOutOfRange {
+            position,
+            vertex: v,
+            num_vertices: nv,
+        })
+    }
+}
+
 impl<G: FastGraph> GraphFormat<G> for FakeDimacs<G> {
-    fn parse_graph<R: std::io::BufRead>(reader: R) -> Result<G, ()> {
+    fn parse_graph_with_max_order<R: std::io::BufRead>(
+        reader: R,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
         let mut pairs = PairIterator::new(reader);
-        let (nv, ne) = pairs.next().ok_or(())?;
+        let ((order, nv), (header, ne)) =
+            pairs.next().ok_or(GraphFormatError::UnexpectedEof {
+                position: pairs.next_position(),
+                expected: "a header of the form `nv ne`",
+            })??;
 
-        let mut graph = G::empty(nv);
+        let mut graph = G::empty(check_order(order, nv, max_order)?);
+        let mut found = 0;
 
-        for (u, v) in pairs.take(ne) {
+        for pair in pairs.by_ref() {
+            let (u, v) = pair?;
+            found += 1;
+            if found > ne {
+                continue;
+            }
+            let u = check_vertex(u, nv)?;
+            let v = check_vertex(v, nv)?;
             unsafe {
                 graph.add_edge_unchecked(u, v);
             }
         }
+
+        if found != ne {
+            return Err(GraphFormatError::EdgeCountMismatch {
+                position: header,
+                expected: ne,
+                found,
+            });
+        }
+        Ok(graph)
+    }
+
+    fn write_graph<W: std::io::Write>(graph: &G, mut writer: W) -> Result<(), GraphFormatError> {
+        let edges = undirected_edges(graph);
+        writeln!(writer, "{} {}", graph.num_v_labels(), edges.len())?;
+        for (u, v) in edges {
+            writeln!(writer, "{u} {v}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The DIMACS graph format, as used by the DIMACS coloring and clique challenges.
+///
+/// ```text
+/// c A comment
+/// p edge 3 2
+/// e 1 2
+/// e 2 3
+/// ```
+///
+/// Vertices are numbered from 1 in the file and from 0 in the parsed graph.
+/// Both `p edge` and `p col` headers are accepted.
+pub struct Dimacs<G: AbstractGraph> {
+    _p: PhantomData<G>,
+}
+
+/// Reads the next token as a vertex number in `1..=nv`, returning its 0-based label.
+fn parse_vertex(tokens: &mut LineTokens, nv: usize) -> Result<usize, GraphFormatError> {
+    let (position, v) = tokens.next_usize("a vertex number")?;
+    if v == 0 || v > nv {
+        return Err(GraphFormatError::VertexOutOfRange {
+            position,
+            vertex: v,
+            num_vertices: nv,
+        });
+    }
+    Ok(v - 1)
+}
+
+impl<G: FastGraph> GraphFormat<G> for Dimacs<G> {
+    fn parse_graph_with_max_order<R: std::io::BufRead>(
+        reader: R,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        // The graph, along with the position and value of the declared edge count
+        let mut header: Option<(G, Position, usize)> = None;
+        let mut found = 0;
+        let mut number = 0;
+
+        for line in reader.lines() {
+            let line = line?;
+            number += 1;
+            let mut tokens = LineTokens::new(&line, number);
+
+            match tokens.next_token() {
+                None | Some((_, "c")) => {}
+                Some((position, "p")) => {
+                    if header.is_some() {
+                        return Err(GraphFormatError::syntax(position, "duplicate problem line"));
+                    }
+                    match tokens.next_token() {
+                        Some((_, "edge" | "col")) => {}
+                        Some((position, token)) => {
+                            return Err(GraphFormatError::MalformedToken {
+                                position,
+                                token: token.to_string(),
+                                expected: "`edge`",
+                            });
+                        }
+                        None => {
+                            return Err(GraphFormatError::MissingToken {
+                                position: tokens.position(),
+                                expected: "`edge`",
+                            });
+                        }
+                    }
+                    let (order, nv) = tokens.next_usize("a vertex count")?;
+                    let nv = check_order(order, nv, max_order)?;
+                    let (position, ne) = tokens.next_usize("an edge count")?;
+                    tokens.finish()?;
+                    header = Some((G::empty(nv), position, ne));
+                }
+                Some((position, "e")) => {
+                    let Some((graph, _, _)) = header.as_mut() else {
+                        return Err(GraphFormatError::syntax(
+                            position,
+                            "edge line before the problem line",
+                        ));
+                    };
+                    let nv = graph.num_v_labels();
+                    let u = parse_vertex(&mut tokens, nv)?;
+                    let v = parse_vertex(&mut tokens, nv)?;
+                    tokens.finish()?;
+                    graph.add_edge(u, v);
+                    found += 1;
+                }
+                Some((position, token)) => {
+                    return Err(GraphFormatError::MalformedToken {
+                        position,
+                        token: token.to_string(),
+                        expected: "a line type of `c`, `p` or `e`",
+                    });
+                }
+            }
+        }
+
+        let (graph, position, expected) = header.ok_or(GraphFormatError::UnexpectedEof {
+            position: Position {
+                line: number + 1,
+                column: 1,
+            },
+            expected: "a problem line",
+        })?;
+        if found != expected {
+            return Err(GraphFormatError::EdgeCountMismatch {
+                position,
+                expected,
+                found,
+            });
+        }
         Ok(graph)
     }
 
-    // TODO: Implement this
-    fn write_graph<W: std::io::Write>(_writer: W) -> Result<(), ()> {
-        todo!()
+    fn write_graph<W: Write>(graph: &G, mut writer: W) -> Result<(), GraphFormatError> {
+        let edges = undirected_edges(graph);
+        writeln!(writer, "p edge {} {}", graph.num_v_labels(), edges.len())?;
+        for (u, v) in edges {
+            writeln!(writer, "e {} {}", u + 1, v + 1)?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,760 @@
+//! The GraphViz DOT language.
+//!
+//! [`DotWriter`] renders any [`ViewCombinator`], with optional labels and colors.
+//! [`DotGraph`] reads the common subset of the language: `graph`/`digraph`, node, edge and attribute
+//! statements, and subgraphs, which are flattened into the parent graph. Attributes are kept as strings.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    hash::Hash,
+    io::{BufRead, Write},
+    iter::Peekable,
+    marker::PhantomData,
+    str::Chars,
+};
+
+use squareknot_graph::{AbstractGraph, FastGraph, ViewCombinator};
+
+use super::{check_order, error::Position, GraphFormat, GraphFormatError};
+
+type VertexFn<'f, L> = Box<dyn Fn(L) -> String + 'f>;
+type EdgeFn<'f, L> = Box<dyn Fn(L, L) -> String + 'f>;
+
+/// Writes views as DOT.
+///
+/// Vertices are identified by the [`Display`] output of their labels.
+/// Undirected output lists each edge once, regardless of how many times the view yields it.
+pub struct DotWriter<'f, L> {
+    directed: bool,
+    name: Option<String>,
+    vertex_label: Option<VertexFn<'f, L>>,
+    vertex_color: Option<VertexFn<'f, L>>,
+    edge_label: Option<EdgeFn<'f, L>>,
+    edge_color: Option<EdgeFn<'f, L>>,
+}
+
+impl<L> Default for DotWriter<'_, L> {
+    fn default() -> Self {
+        Self {
+            directed: false,
+            name: None,
+            vertex_label: None,
+            vertex_color: None,
+            edge_label: None,
+            edge_color: None,
+        }
+    }
+}
+
+impl<'f, L: Copy + Hash + Eq + Display> DotWriter<'f, L> {
+    /// Creates a writer for undirected graphs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes a `digraph` if `directed` is set, and a `graph` otherwise.
+    pub fn directed(mut self, directed: bool) -> Self {
+        self.directed = directed;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn vertex_label(mut self, f: impl Fn(L) -> String + 'f) -> Self {
+        self.vertex_label = Some(Box::new(f));
+        self
+    }
+
+    pub fn vertex_color(mut self, f: impl Fn(L) -> String + 'f) -> Self {
+        self.vertex_color = Some(Box::new(f));
+        self
+    }
+
+    pub fn edge_label(mut self, f: impl Fn(L, L) -> String + 'f) -> Self {
+        self.edge_label = Some(Box::new(f));
+        self
+    }
+
+    pub fn edge_color(mut self, f: impl Fn(L, L) -> String + 'f) -> Self {
+        self.edge_color = Some(Box::new(f));
+        self
+    }
+
+    pub fn write<V: ViewCombinator<VertexLabel = L>, W: Write>(
+        &self,
+        view: &V,
+        mut writer: W,
+    ) -> Result<(), GraphFormatError> {
+        let (keyword, edge_op) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        match &self.name {
+            Some(name) => writeln!(writer, "{keyword} {} {{", quote(name))?,
+            None => writeln!(writer, "{keyword} {{")?,
+        }
+
+        let mut seen = HashSet::new();
+        for v in view.vertex_iterator() {
+            if !seen.insert(v) {
+                continue;
+            }
+            let id = quote(&v.to_string());
+            let attributes = [
+                ("label", self.vertex_label.as_ref().map(|f| f(v))),
+                ("color", self.vertex_color.as_ref().map(|f| f(v))),
+            ];
+            writeln!(writer, "    {id}{};", attribute_list(&attributes))?;
+        }
+
+        let mut seen = HashSet::new();
+        for (u, v) in view.edge_iterator() {
+            if seen.contains(&(u, v)) || (!self.directed && seen.contains(&(v, u))) {
+                continue;
+            }
+            seen.insert((u, v));
+            let attributes = [
+                ("label", self.edge_label.as_ref().map(|f| f(u, v))),
+                ("color", self.edge_color.as_ref().map(|f| f(u, v))),
+            ];
+            writeln!(
+                writer,
+                "    {} {edge_op} {}{};",
+                quote(&u.to_string()),
+                quote(&v.to_string()),
+                attribute_list(&attributes)
+            )?;
+        }
+
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
+}
+
+/// Quotes an identifier unless it is a plain alphanumeric identifier or a number.
+fn quote(id: &str) -> String {
+    let plain = !id.is_empty()
+        && (id.chars().all(|c| c.is_ascii_digit())
+            || (id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !id.starts_with(|c: char| c.is_ascii_digit())
+                && !is_keyword(id)));
+    if plain {
+        return id.to_string();
+    }
+    let mut quoted = String::with_capacity(id.len() + 2);
+    quoted.push('"');
+    for c in id.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn attribute_list(attributes: &[(&str, Option<String>)]) -> String {
+    let present = attributes
+        .iter()
+        .filter_map(|(key, value)| {
+            value
+                .as_ref()
+                .map(|value| format!("{key}={}", quote(value)))
+        })
+        .collect::<Vec<_>>();
+    if present.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", present.join(", "))
+    }
+}
+
+fn is_keyword(id: &str) -> bool {
+    ["strict", "graph", "digraph", "node", "edge", "subgraph"]
+        .iter()
+        .any(|k| k.eq_ignore_ascii_case(id))
+}
+
+/// A node of a [`DotGraph`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DotNode {
+    pub id: String,
+    pub attributes: HashMap<String, String>,
+}
+
+/// An edge of a [`DotGraph`], between the nodes at indices `source` and `target`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DotEdge {
+    pub source: usize,
+    pub target: usize,
+    pub attributes: HashMap<String, String>,
+}
+
+/// A graph read from DOT.
+///
+/// Nodes are indexed in order of first appearance. Node and edge attributes include defaults set by
+/// `node [...]` and `edge [...]` statements in scope when the node or edge was created.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DotGraph {
+    pub name: Option<String>,
+    pub directed: bool,
+    pub strict: bool,
+    pub attributes: HashMap<String, String>,
+    pub nodes: Vec<DotNode>,
+    pub edges: Vec<DotEdge>,
+    indices: HashMap<String, usize>,
+}
+
+impl DotGraph {
+    pub fn parse<R: BufRead>(mut reader: R) -> Result<Self, GraphFormatError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse_str(&input)
+    }
+
+    pub fn parse_str(input: &str) -> Result<Self, GraphFormatError> {
+        let tokens = Lexer::new(input).tokenize()?;
+        Parser {
+            tokens,
+            index: 0,
+            graph: DotGraph::default(),
+        }
+        .parse()
+    }
+
+    /// Returns the index of the node with the given id.
+    pub fn node_index(&self, id: &str) -> Option<usize> {
+        self.indices.get(id).copied()
+    }
+
+    /// Builds a graph with one vertex per node. Self-loops are dropped.
+    ///
+    /// Undirected edges are added in both directions, so a directed graph type receives both arcs.
+    pub fn to_graph<G: FastGraph>(&self) -> G {
+        let mut graph = G::empty(self.nodes.len());
+        for edge in self.edges.iter().filter(|e| e.source != e.target) {
+            graph.add_edge(edge.source, edge.target);
+            if !self.directed {
+                graph.add_edge(edge.target, edge.source);
+            }
+        }
+        graph
+    }
+
+    fn node(&mut self, id: String, defaults: &HashMap<String, String>) -> usize {
+        if let Some(&index) = self.indices.get(&id) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(id.clone(), index);
+        self.nodes.push(DotNode {
+            id,
+            attributes: defaults.clone(),
+        });
+        index
+    }
+}
+
+/// Reads graphs from DOT, and writes them as a `graph` if every edge is paired with its reverse,
+/// and as a `digraph` otherwise.
+pub struct Dot<G: AbstractGraph> {
+    _p: PhantomData<G>,
+}
+
+impl<G: FastGraph> GraphFormat<G> for Dot<G> {
+    fn parse_graph_with_max_order<R: BufRead>(
+        reader: R,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        let graph = DotGraph::parse(reader)?;
+        check_order(
+            Position { line: 1, column: 1 },
+            graph.nodes.len(),
+            max_order,
+        )?;
+        Ok(graph.to_graph())
+    }
+
+    fn write_graph<W: Write>(graph: &G, writer: W) -> Result<(), GraphFormatError> {
+        let directed = !graph.edge_iterator().all(|(u, v)| graph.has_edge(v, u));
+        DotWriter::new()
+            .directed(directed)
+            .write(&graph.view(), writer)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    /// An identifier, numeral, quoted string or HTML string. Only unquoted identifiers may be keywords.
+    Id {
+        text: String,
+        quoted: bool,
+    },
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Semicolon,
+    Comma,
+    Equals,
+    Colon,
+    Plus,
+    /// `->` if directed, and `--` otherwise.
+    EdgeOp {
+        directed: bool,
+    },
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Id { text, .. } => text.clone(),
+            Token::LBrace => "{".into(),
+            Token::RBrace => "}".into(),
+            Token::LBracket => "[".into(),
+            Token::RBracket => "]".into(),
+            Token::Semicolon => ";".into(),
+            Token::Comma => ",".into(),
+            Token::Equals => "=".into(),
+            Token::Colon => ":".into(),
+            Token::Plus => "+".into(),
+            Token::EdgeOp { directed: true } => "->".into(),
+            Token::EdgeOp { directed: false } => "--".into(),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Id { text, quoted: false } if text.eq_ignore_ascii_case(keyword))
+    }
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+    line_start: bool,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+            line: 1,
+            column: 1,
+            line_start: true,
+        }
+    }
+
+    fn position(&self) -> Position {
+        Position {
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+            self.line_start = true;
+        } else {
+            self.column += 1;
+            if !c.is_whitespace() {
+                self.line_start = false;
+            }
+        }
+        Some(c)
+    }
+
+    fn tokenize(mut self) -> Result<Vec<(Position, Token)>, GraphFormatError> {
+        let mut tokens = vec![];
+        while let Some(&c) = self.chars.peek() {
+            let position = self.position();
+            if c.is_whitespace() {
+                self.bump();
+                continue;
+            }
+            // Lines beginning with `#` are C preprocessor output, and are ignored
+            if c == '#' && self.line_start {
+                while self.chars.peek().is_some_and(|&c| c != '\n') {
+                    self.bump();
+                }
+                continue;
+            }
+            self.bump();
+            let token = match c {
+                '{' => Token::LBrace,
+                '}' => Token::RBrace,
+                '[' => Token::LBracket,
+                ']' => Token::RBracket,
+                ';' => Token::Semicolon,
+                ',' => Token::Comma,
+                '=' => Token::Equals,
+                ':' => Token::Colon,
+                '+' => Token::Plus,
+                '/' if self.chars.peek() == Some(&'/') => {
+                    while self.chars.peek().is_some_and(|&c| c != '\n') {
+                        self.bump();
+                    }
+                    continue;
+                }
+                '/' if self.chars.peek() == Some(&'*') => {
+                    self.bump();
+                    let mut previous = ' ';
+                    loop {
+                        match self.bump() {
+                            Some('/') if previous == '*' => break,
+                            Some(c) => previous = c,
+                            None => {
+                                return Err(GraphFormatError::UnexpectedEof {
+                                    position: self.position(),
+                                    expected: "the end of a comment",
+                                })
+                            }
+                        }
+                    }
+                    continue;
+                }
+                '-' if self.chars.peek() == Some(&'-') => {
+                    self.bump();
+                    Token::EdgeOp { directed: false }
+                }
+                '-' if self.chars.peek() == Some(&'>') => {
+                    self.bump();
+                    Token::EdgeOp { directed: true }
+                }
+                '"' => Token::Id {
+                    text: self.quoted(position)?,
+                    quoted: true,
+                },
+                '<' => Token::Id {
+                    text: self.html(position)?,
+                    quoted: true,
+                },
+                c if c == '-' || c == '.' || c == '_' || c.is_alphanumeric() => {
+                    let mut text = String::from(c);
+                    while let Some(&c) = self.chars.peek() {
+                        if c == '.' || c == '_' || c.is_alphanumeric() {
+                            text.push(c);
+                            self.bump();
+                        } else {
+                            break;
+                        }
+                    }
+                    Token::Id {
+                        text,
+                        quoted: false,
+                    }
+                }
+                c => {
+                    return Err(GraphFormatError::MalformedToken {
+                        position,
+                        token: c.to_string(),
+                        expected: "a DOT token",
+                    })
+                }
+            };
+            tokens.push((position, token));
+        }
+        tokens.push((self.position(), Token::Semicolon));
+        Ok(tokens)
+    }
+
+    /// Reads the rest of a double-quoted string. Only `\"`, `\\` and escaped newlines are unescaped;
+    /// other escapes are kept for GraphViz to interpret.
+    fn quoted(&mut self, start: Position) -> Result<String, GraphFormatError> {
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(text),
+                Some('\\') => match self.bump() {
+                    Some(c @ ('"' | '\\')) => text.push(c),
+                    Some('\n') => {}
+                    Some(c) => {
+                        text.push('\\');
+                        text.push(c);
+                    }
+                    None => break,
+                },
+                Some(c) => text.push(c),
+                None => break,
+            }
+        }
+        Err(GraphFormatError::syntax(start, "unterminated string"))
+    }
+
+    /// Reads the rest of an HTML string, without the outermost angle brackets.
+    fn html(&mut self, start: Position) -> Result<String, GraphFormatError> {
+        let mut text = String::new();
+        let mut depth = 1;
+        while let Some(c) = self.bump() {
+            match c {
+                '<' => depth += 1,
+                '>' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(text);
+                    }
+                }
+                _ => {}
+            }
+            text.push(c);
+        }
+        Err(GraphFormatError::syntax(start, "unterminated HTML string"))
+    }
+}
+
+/// Default attributes set by `node [...]` and `edge [...]`, scoped to the enclosing subgraph.
+#[derive(Clone, Default)]
+struct Defaults {
+    node: HashMap<String, String>,
+    edge: HashMap<String, String>,
+}
+
+struct Parser {
+    /// Every token, followed by a sentinel `;` at the end of the input.
+    tokens: Vec<(Position, Token)>,
+    index: usize,
+    graph: DotGraph,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index].1
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.index + offset).map(|(_, t)| t)
+    }
+
+    fn position(&self) -> Position {
+        self.tokens[self.index].0
+    }
+
+    fn at_end(&self) -> bool {
+        self.index + 1 == self.tokens.len()
+    }
+
+    fn unexpected(&self, expected: &'static str) -> GraphFormatError {
+        if self.at_end() {
+            GraphFormatError::UnexpectedEof {
+                position: self.position(),
+                expected,
+            }
+        } else {
+            GraphFormatError::MalformedToken {
+                position: self.position(),
+                token: self.peek().describe(),
+                expected,
+            }
+        }
+    }
+
+    fn eat(&mut self, token: Token) -> bool {
+        if !self.at_end() && *self.peek() == token {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: &'static str) -> Result<(), GraphFormatError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek().is_keyword(keyword) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads an identifier, concatenating quoted strings joined by `+`.
+    fn id(&mut self, expected: &'static str) -> Result<String, GraphFormatError> {
+        let Token::Id { text, quoted } = self.peek().clone() else {
+            return Err(self.unexpected(expected));
+        };
+        if self.at_end() {
+            return Err(self.unexpected(expected));
+        }
+        self.index += 1;
+        let mut text = text;
+        while quoted
+            && *self.peek() == Token::Plus
+            && matches!(self.peek_at(1), Some(Token::Id { quoted: true, .. }))
+        {
+            self.index += 1;
+            if let Token::Id { text: next, .. } = self.peek() {
+                text.push_str(next);
+            }
+            self.index += 1;
+        }
+        Ok(text)
+    }
+
+    fn parse(mut self) -> Result<DotGraph, GraphFormatError> {
+        self.graph.strict = self.eat_keyword("strict");
+        self.graph.directed = if self.eat_keyword("digraph") {
+            true
+        } else if self.eat_keyword("graph") {
+            false
+        } else {
+            return Err(self.unexpected("`graph` or `digraph`"));
+        };
+        if matches!(self.peek(), Token::Id { .. }) && !self.at_end() {
+            self.graph.name = Some(self.id("a graph name")?);
+        }
+        self.expect(Token::LBrace, "`{`")?;
+        self.statements(&mut Defaults::default())?;
+        if !self.at_end() {
+            return Err(self.unexpected("the end of the input"));
+        }
+        Ok(self.graph)
+    }
+
+    /// Parses statements up to and including the closing brace, returning every node they mention.
+    fn statements(&mut self, defaults: &mut Defaults) -> Result<Vec<usize>, GraphFormatError> {
+        let mut nodes = vec![];
+        while !self.eat(Token::RBrace) {
+            if self.at_end() {
+                return Err(self.unexpected("`}`"));
+            }
+            self.statement(defaults, &mut nodes)?;
+            self.eat(Token::Semicolon);
+        }
+        Ok(nodes)
+    }
+
+    fn statement(
+        &mut self,
+        defaults: &mut Defaults,
+        nodes: &mut Vec<usize>,
+    ) -> Result<(), GraphFormatError> {
+        if self.peek_at(1) == Some(&Token::LBracket) {
+            if self.eat_keyword("graph") {
+                let attributes = self.attributes()?;
+                self.graph.attributes.extend(attributes);
+                return Ok(());
+            } else if self.eat_keyword("node") {
+                let attributes = self.attributes()?;
+                defaults.node.extend(attributes);
+                return Ok(());
+            } else if self.eat_keyword("edge") {
+                let attributes = self.attributes()?;
+                defaults.edge.extend(attributes);
+                return Ok(());
+            }
+        }
+        if matches!(self.peek(), Token::Id { .. })
+            && !self.peek().is_keyword("subgraph")
+            && self.peek_at(1) == Some(&Token::Equals)
+        {
+            let key = self.id("an attribute name")?;
+            self.index += 1;
+            let value = self.id("an attribute value")?;
+            self.graph.attributes.insert(key, value);
+            return Ok(());
+        }
+
+        let (is_node, first) = self.endpoint(defaults)?;
+        nodes.extend(&first);
+        let mut groups = vec![first];
+        while let Token::EdgeOp { directed } = *self.peek() {
+            if directed != self.graph.directed {
+                return Err(GraphFormatError::syntax(
+                    self.position(),
+                    if directed {
+                        "`->` used in an undirected graph"
+                    } else {
+                        "`--` used in a directed graph"
+                    },
+                ));
+            }
+            self.index += 1;
+            let (_, next) = self.endpoint(defaults)?;
+            nodes.extend(&next);
+            groups.push(next);
+        }
+        let attributes = if *self.peek() == Token::LBracket {
+            self.attributes()?
+        } else {
+            HashMap::new()
+        };
+
+        if groups.len() == 1 {
+            if is_node {
+                self.graph.nodes[groups[0][0]].attributes.extend(attributes);
+            }
+            return Ok(());
+        }
+        let mut edge_attributes = defaults.edge.clone();
+        edge_attributes.extend(attributes);
+        for pair in groups.windows(2) {
+            for &source in &pair[0] {
+                for &target in &pair[1] {
+                    self.graph.edges.push(DotEdge {
+                        source,
+                        target,
+                        attributes: edge_attributes.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a node id or a subgraph, returning whether it was a single node, and the nodes it contains.
+    fn endpoint(&mut self, defaults: &Defaults) -> Result<(bool, Vec<usize>), GraphFormatError> {
+        if self.eat_keyword("subgraph") {
+            if matches!(self.peek(), Token::Id { .. }) && !self.at_end() {
+                self.id("a subgraph name")?;
+            }
+            self.expect(Token::LBrace, "`{`")?;
+            return Ok((false, self.statements(&mut defaults.clone())?));
+        }
+        if self.eat(Token::LBrace) {
+            return Ok((false, self.statements(&mut defaults.clone())?));
+        }
+
+        let id = self.id("a node id, a subgraph or an attribute statement")?;
+        // Ports and compass points do not affect the structure of the graph
+        for _ in 0..2 {
+            if self.eat(Token::Colon) {
+                self.id("a port")?;
+            }
+        }
+        Ok((true, vec![self.graph.node(id, &defaults.node)]))
+    }
+
+    /// Parses one or more bracketed attribute lists.
+    fn attributes(&mut self) -> Result<HashMap<String, String>, GraphFormatError> {
+        let mut attributes = HashMap::new();
+        while self.eat(Token::LBracket) {
+            while !self.eat(Token::RBracket) {
+                let key = self.id("an attribute name or `]`")?;
+                self.expect(Token::Equals, "`=`")?;
+                let value = self.id("an attribute value")?;
+                attributes.insert(key, value);
+                if !self.eat(Token::Semicolon) {
+                    self.eat(Token::Comma);
+                }
+            }
+        }
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,139 @@
+use std::{error::Error, fmt::Display};
+
+/// A position in a text input. Both fields are 1-based, and columns count characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// An error produced while reading or writing a graph.
+#[derive(Debug)]
+pub enum GraphFormatError {
+    /// The underlying reader or writer failed.
+    Io(std::io::Error),
+    /// A token could not be interpreted.
+    MalformedToken {
+        position: Position,
+        token: String,
+        expected: &'static str,
+    },
+    /// A line ended before a required token.
+    MissingToken {
+        position: Position,
+        expected: &'static str,
+    },
+    /// A vertex index does not refer to a vertex of the graph.
+    VertexOutOfRange {
+        position: Position,
+        vertex: usize,
+        num_vertices: usize,
+    },
+    /// The number of edges does not match the number declared in the header.
+    EdgeCountMismatch {
+        position: Position,
+        expected: usize,
+        found: usize,
+    },
+    /// The input ended before a required element.
+    UnexpectedEof {
+        position: Position,
+        expected: &'static str,
+    },
+    /// The input is structurally invalid for the format.
+    Syntax { position: Position, reason: String },
+    /// The graph has more vertices than the parser was allowed to allocate.
+    OrderTooLarge {
+        position: Position,
+        order: usize,
+        max_order: usize,
+    },
+}
+
+impl GraphFormatError {
+    /// Returns the position in the input at which the error was detected, if there is one.
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Self::Io(_) => None,
+            Self::MalformedToken { position, .. }
+            | Self::MissingToken { position, .. }
+            | Self::VertexOutOfRange { position, .. }
+            | Self::EdgeCountMismatch { position, .. }
+            | Self::UnexpectedEof { position, .. }
+            | Self::Syntax { position, .. }
+            | Self::OrderTooLarge { position, .. } => Some(*position),
+        }
+    }
+
+    pub(crate) fn syntax(position: Position, reason: impl Into<String>) -> Self {
+        Self::Syntax {
+            position,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for GraphFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::MalformedToken {
+                position,
+                token,
+                expected,
+            } => write!(f, "{position}: expected {expected}, found `{token}`"),
+            Self::MissingToken { position, expected } => {
+                write!(f, "{position}: expected {expected}, found end of line")
+            }
+            Self::VertexOutOfRange {
+                position,
+                vertex,
+                num_vertices,
+            } => write!(
+                f,
+                "{position}: vertex {vertex} is out of range for a graph on {num_vertices} vertices"
+            ),
+            Self::EdgeCountMismatch {
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{position}: header declares {expected} edges, but {found} were found"
+            ),
+            Self::UnexpectedEof { position, expected } => {
+                write!(f, "{position}: expected {expected}, found end of input")
+            }
+            Self::Syntax { position, reason } => write!(f, "{position}: {reason}"),
+            Self::OrderTooLarge {
+                position,
+                order,
+                max_order,
+            } => write!(
+                f,
+                "{position}: graph on {order} vertices exceeds the maximum of {max_order}"
+            ),
+        }
+    }
+}
+
+impl Error for GraphFormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for GraphFormatError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
@@ -0,0 +1,546 @@
+//! The graph6, sparse6 and digraph6 formats, as described in the nauty documentation.
+//!
+//! Each graph is encoded on a single line of printable ASCII, so files often hold many graphs.
+//! [`LineFormat::graphs`] iterates over such files one graph at a time.
+
+use std::{
+    io::{BufRead, Write},
+    marker::PhantomData,
+};
+
+use squareknot_graph::{AbstractGraph, FastGraph};
+
+use super::{
+    check_order, error::Position, undirected_edges, GraphFormat, GraphFormatError,
+    DEFAULT_MAX_ORDER,
+};
+
+/// A graph format which encodes each graph on a single line.
+pub trait LineFormat<G: AbstractGraph>: GraphFormat<G> + Sized {
+    /// The optional header which may precede the first graph of a file.
+    const HEADER: &'static str;
+
+    /// Parses a single encoded graph with at most [`DEFAULT_MAX_ORDER`] vertices.
+    /// `line` is the 1-based line number used in errors.
+    fn parse_line(encoded: &str, line: usize) -> Result<G, GraphFormatError> {
+        Self::parse_line_with_max_order(encoded, line, DEFAULT_MAX_ORDER)
+    }
+
+    /// Parses a single encoded graph, failing with [`GraphFormatError::OrderTooLarge`]
+    /// if it has more than `max_order` vertices.
+    fn parse_line_with_max_order(
+        encoded: &str,
+        line: usize,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError>;
+
+    /// Encodes a single graph, without a trailing newline.
+    fn encode(graph: &G) -> String;
+
+    /// Iterates over the graphs of a file, one per line, each with at most [`DEFAULT_MAX_ORDER`] vertices.
+    fn graphs<R: BufRead>(reader: R) -> GraphLines<R, G, Self> {
+        Self::graphs_with_max_order(reader, DEFAULT_MAX_ORDER)
+    }
+
+    /// Iterates over the graphs of a file, one per line, each with at most `max_order` vertices.
+    fn graphs_with_max_order<R: BufRead>(reader: R, max_order: usize) -> GraphLines<R, G, Self> {
+        GraphLines {
+            reader,
+            buffer: String::new(),
+            line: 0,
+            max_order,
+            _p: PhantomData,
+        }
+    }
+
+    /// Writes each graph on its own line.
+    fn write_graphs<'g, W: Write, I: IntoIterator<Item = &'g G>>(
+        graphs: I,
+        mut writer: W,
+    ) -> Result<(), GraphFormatError>
+    where
+        G: 'g,
+    {
+        for graph in graphs {
+            writeln!(writer, "{}", Self::encode(graph))?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the graphs of a file in a [`LineFormat`], one per line.
+/// Blank lines and headers are skipped.
+pub struct GraphLines<R: BufRead, G: AbstractGraph, F: LineFormat<G>> {
+    reader: R,
+    buffer: String,
+    line: usize,
+    max_order: usize,
+    _p: PhantomData<(G, F)>,
+}
+
+impl<R: BufRead, G: AbstractGraph, F: LineFormat<G>> Iterator for GraphLines<R, G, F> {
+    type Item = Result<G, GraphFormatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => self.line += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+            let encoded = self.buffer.trim_end_matches(['\n', '\r']);
+            let encoded = encoded.strip_prefix(F::HEADER).unwrap_or(encoded);
+            if !encoded.is_empty() {
+                return Some(F::parse_line_with_max_order(
+                    encoded,
+                    self.line,
+                    self.max_order,
+                ));
+            }
+        }
+    }
+}
+
+/// Parses the first graph of a file in a [`LineFormat`].
+fn parse_first<R: BufRead, G: AbstractGraph, F: LineFormat<G>>(
+    reader: R,
+    max_order: usize,
+) -> Result<G, GraphFormatError> {
+    let mut lines = F::graphs_with_max_order(reader, max_order);
+    lines.next().unwrap_or(Err(GraphFormatError::UnexpectedEof {
+        position: Position {
+            line: lines.line + 1,
+            column: 1,
+        },
+        expected: "a graph",
+    }))
+}
+
+/// Reads 6-bit groups from an encoded graph.
+struct SixBitReader<'a> {
+    bytes: &'a [u8],
+    index: usize,
+    line: usize,
+}
+
+impl<'a> SixBitReader<'a> {
+    fn new(encoded: &'a str, line: usize) -> Self {
+        Self {
+            bytes: encoded.as_bytes(),
+            index: 0,
+            line,
+        }
+    }
+
+    fn position(&self) -> Position {
+        Position {
+            line: self.line,
+            column: self.index + 1,
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.index
+    }
+
+    /// Reads one byte and returns its 6-bit value.
+    fn next(&mut self, expected: &'static str) -> Result<u64, GraphFormatError> {
+        let position = self.position();
+        let byte = *self
+            .bytes
+            .get(self.index)
+            .ok_or(GraphFormatError::MissingToken { position, expected })?;
+        if !(63..=126).contains(&byte) {
+            return Err(GraphFormatError::MalformedToken {
+                position,
+                token: String::from_utf8_lossy(&self.bytes[self.index..=self.index]).into_owned(),
+                expected: "a character between `?` and `~`",
+            });
+        }
+        self.index += 1;
+        Ok((byte - 63) as u64)
+    }
+
+    /// Reads the number of vertices, `N(n)` in the specification, which may be at most `max_order`.
+    fn order(&mut self, max_order: usize) -> Result<usize, GraphFormatError> {
+        let start = self.position();
+        let first = self.next("the number of vertices")?;
+        if first != 63 {
+            return check_order(start, first as usize, max_order);
+        }
+        let count = if self.bytes.get(self.index) == Some(&126) {
+            self.index += 1;
+            6
+        } else {
+            3
+        };
+        let mut n = 0;
+        for _ in 0..count {
+            n = (n << 6) | self.next("the number of vertices")?;
+        }
+        let n = usize::try_from(n).map_err(|_| Self::too_large(start))?;
+        check_order(start, n, max_order)
+    }
+
+    fn too_large(position: Position) -> GraphFormatError {
+        GraphFormatError::syntax(position, "the number of vertices is too large")
+    }
+
+    /// Fails unless exactly enough bytes remain to hold `bits` bits of adjacency data.
+    /// `None` stands for a number of bits which overflowed, so that the graph could never be stored.
+    fn expect_bits(&self, bits: Option<usize>) -> Result<(), GraphFormatError> {
+        match bits {
+            Some(bits) => self.expect_remaining(bits.div_ceil(6)),
+            None => Err(Self::too_large(Position {
+                line: self.line,
+                column: 1,
+            })),
+        }
+    }
+
+    /// Fails unless exactly `count` bytes remain.
+    fn expect_remaining(&self, count: usize) -> Result<(), GraphFormatError> {
+        match self.remaining().cmp(&count) {
+            std::cmp::Ordering::Equal => Ok(()),
+            std::cmp::Ordering::Less => Err(GraphFormatError::MissingToken {
+                position: Position {
+                    line: self.line,
+                    column: self.bytes.len() + 1,
+                },
+                expected: "more adjacency data",
+            }),
+            std::cmp::Ordering::Greater => Err(GraphFormatError::syntax(
+                Position {
+                    line: self.line,
+                    column: self.index + count + 1,
+                },
+                format!(
+                    "expected {count} bytes of adjacency data, found {}",
+                    self.remaining()
+                ),
+            )),
+        }
+    }
+
+    /// Iterates over the remaining bits, most significant first.
+    fn bits(mut self) -> impl Iterator<Item = Result<bool, GraphFormatError>> + 'a {
+        let mut group = 0;
+        let mut left = 0;
+        std::iter::from_fn(move || {
+            if left == 0 {
+                if self.remaining() == 0 {
+                    return None;
+                }
+                match self.next("adjacency data") {
+                    Ok(g) => group = g,
+                    Err(e) => return Some(Err(e)),
+                }
+                left = 6;
+            }
+            left -= 1;
+            Some(Ok((group >> left) & 1 == 1))
+        })
+    }
+}
+
+/// Accumulates bits into 6-bit groups of printable characters.
+struct SixBitWriter {
+    output: String,
+    group: u8,
+    used: u32,
+}
+
+impl SixBitWriter {
+    fn new(prefix: &str) -> Self {
+        Self {
+            output: String::from(prefix),
+            group: 0,
+            used: 0,
+        }
+    }
+
+    /// Writes the number of vertices, `N(n)` in the specification.
+    fn order(&mut self, n: usize) {
+        let n = n as u64;
+        let count = if n <= 62 {
+            self.output.push((n as u8 + 63) as char);
+            0
+        } else if n <= 258047 {
+            self.output.push('~');
+            3
+        } else {
+            self.output.push_str("~~");
+            6
+        };
+        for i in (0..count).rev() {
+            self.output.push((((n >> (6 * i)) & 63) as u8 + 63) as char);
+        }
+    }
+
+    /// The number of bits which may be written before the current group is complete.
+    fn free(&self) -> u32 {
+        6 - self.used
+    }
+
+    fn push(&mut self, bit: bool) {
+        self.group = (self.group << 1) | bit as u8;
+        self.used += 1;
+        if self.used == 6 {
+            self.output.push((self.group + 63) as char);
+            self.group = 0;
+            self.used = 0;
+        }
+    }
+
+    /// Writes the lowest `count` bits of `value`, most significant first.
+    fn push_bits(&mut self, value: usize, count: u32) {
+        for i in (0..count).rev() {
+            self.push((value >> i) & 1 == 1);
+        }
+    }
+
+    /// Pads the final group with `pad` bits and returns the encoded string.
+    fn finish(mut self, pad: bool) -> String {
+        while self.used != 0 {
+            self.push(pad);
+        }
+        self.output
+    }
+}
+
+/// The graph6 format for undirected graphs.
+pub struct Graph6<G: AbstractGraph> {
+    _p: PhantomData<G>,
+}
+
+impl<G: FastGraph> LineFormat<G> for Graph6<G> {
+    const HEADER: &'static str = ">>graph6<<";
+
+    fn parse_line_with_max_order(
+        encoded: &str,
+        line: usize,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        let mut reader = SixBitReader::new(encoded, line);
+        let n = reader.order(max_order)?;
+        // The adjacency data must be present before any storage for it is allocated
+        reader.expect_bits(n.checked_mul(n.saturating_sub(1)).map(|bits| bits / 2))?;
+
+        let mut graph = G::empty(n);
+        let pairs = (1..n).flat_map(|j| (0..j).map(move |i| (i, j)));
+        for ((i, j), bit) in pairs.zip(reader.bits()) {
+            if bit? {
+                unsafe {
+                    graph.add_edge_unchecked(i, j);
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    fn encode(graph: &G) -> String {
+        let n = graph.num_v_labels();
+        let bits = n
+            .checked_mul(n.saturating_sub(1))
+            .expect("the graph is too large for graph6")
+            / 2;
+        let mut matrix = vec![false; bits];
+        for (u, v) in graph.edge_iterator().filter(|&(u, v)| u != v) {
+            let (i, j) = (u.min(v), u.max(v));
+            matrix[j * (j - 1) / 2 + i] = true;
+        }
+
+        let mut writer = SixBitWriter::new("");
+        writer.order(n);
+        for bit in matrix {
+            writer.push(bit);
+        }
+        writer.finish(false)
+    }
+}
+
+impl<G: FastGraph> GraphFormat<G> for Graph6<G> {
+    fn parse_graph_with_max_order<R: BufRead>(
+        reader: R,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        parse_first::<R, G, Self>(reader, max_order)
+    }
+
+    fn write_graph<W: Write>(graph: &G, mut writer: W) -> Result<(), GraphFormatError> {
+        writeln!(writer, "{}", Self::encode(graph))?;
+        Ok(())
+    }
+}
+
+/// The digraph6 format for directed graphs.
+/// Self-loops are ignored when parsing.
+pub struct Digraph6<G: AbstractGraph> {
+    _p: PhantomData<G>,
+}
+
+impl<G: FastGraph> LineFormat<G> for Digraph6<G> {
+    const HEADER: &'static str = ">>digraph6<<";
+
+    fn parse_line_with_max_order(
+        encoded: &str,
+        line: usize,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        let Some(body) = encoded.strip_prefix('&') else {
+            return Err(GraphFormatError::MalformedToken {
+                position: Position { line, column: 1 },
+                token: encoded.chars().take(1).collect(),
+                expected: "`&`",
+            });
+        };
+        let mut reader = SixBitReader::new(body, line);
+        let n = reader.order(max_order)?;
+        reader.expect_bits(n.checked_mul(n))?;
+
+        let mut graph = G::empty(n);
+        let pairs = (0..n).flat_map(|i| (0..n).map(move |j| (i, j)));
+        for ((i, j), bit) in pairs.zip(reader.bits()) {
+            if bit? && i != j {
+                graph.add_edge(i, j);
+            }
+        }
+        Ok(graph)
+    }
+
+    fn encode(graph: &G) -> String {
+        let n = graph.num_v_labels();
+        let bits = n
+            .checked_mul(n)
+            .expect("the graph is too large for digraph6");
+        let mut matrix = vec![false; bits];
+        for (u, v) in graph.edge_iterator() {
+            matrix[u * n + v] = true;
+        }
+
+        let mut writer = SixBitWriter::new("&");
+        writer.order(n);
+        for bit in matrix {
+            writer.push(bit);
+        }
+        writer.finish(false)
+    }
+}
+
+impl<G: FastGraph> GraphFormat<G> for Digraph6<G> {
+    fn parse_graph_with_max_order<R: BufRead>(
+        reader: R,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        parse_first::<R, G, Self>(reader, max_order)
+    }
+
+    fn write_graph<W: Write>(graph: &G, mut writer: W) -> Result<(), GraphFormatError> {
+        writeln!(writer, "{}", Self::encode(graph))?;
+        Ok(())
+    }
+}
+
+/// The sparse6 format for undirected graphs, which is more compact than graph6 for sparse graphs.
+/// Self-loops and repeated edges are ignored when parsing.
+///
+/// Isolated vertices take no space in the encoding, so the number of vertices in the header is not bounded by
+/// the length of the input. It is bounded by the maximum order given to the parser instead.
+pub struct Sparse6<G: AbstractGraph> {
+    _p: PhantomData<G>,
+}
+
+/// The number of bits needed to represent `n - 1`.
+fn sparse6_width(n: usize) -> u32 {
+    usize::BITS - n.saturating_sub(1).leading_zeros()
+}
+
+impl<G: FastGraph> LineFormat<G> for Sparse6<G> {
+    const HEADER: &'static str = ">>sparse6<<";
+
+    fn parse_line_with_max_order(
+        encoded: &str,
+        line: usize,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        let Some(body) = encoded.strip_prefix(':') else {
+            return Err(GraphFormatError::MalformedToken {
+                position: Position { line, column: 1 },
+                token: encoded.chars().take(1).collect(),
+                expected: "`:`",
+            });
+        };
+        let mut reader = SixBitReader::new(body, line);
+        let n = reader.order(max_order)?;
+        let k = sparse6_width(n) as usize;
+
+        let bits = reader.bits().collect::<Result<Vec<_>, _>>()?;
+        let mut graph = G::empty(n);
+        let mut v = 0;
+
+        // Each entry is one bit `b` followed by `k` bits `x`. Trailing padding is too short to form an entry,
+        // or moves `v` past the last vertex.
+        for entry in bits.chunks_exact(k + 1) {
+            if entry[0] {
+                v += 1;
+            }
+            let x = entry[1..].iter().fold(0, |x, &bit| (x << 1) | bit as usize);
+            if x > v {
+                v = x;
+            } else if v < n && x != v {
+                graph.add_edge(x, v);
+            }
+        }
+        Ok(graph)
+    }
+
+    fn encode(graph: &G) -> String {
+        let n = graph.num_v_labels();
+        let k = sparse6_width(n);
+        let mut edges = undirected_edges(graph)
+            .into_iter()
+            .filter(|&(u, v)| u != v)
+            .map(|(u, v)| (u.max(v), u.min(v)))
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+
+        let mut writer = SixBitWriter::new(":");
+        writer.order(n);
+        let mut current = 0;
+        for (v, u) in edges {
+            if v == current {
+                writer.push(false);
+            } else if v == current + 1 {
+                writer.push(true);
+            } else {
+                writer.push(true);
+                writer.push_bits(v, k);
+                writer.push(false);
+            }
+            writer.push_bits(u, k);
+            current = v;
+        }
+
+        // Padding with ones could be read as an edge to vertex `n - 1` in this case, so a zero is written first
+        let free = writer.free();
+        if free != 6 && free > k && current + 2 == n && n == 1 << k {
+            writer.push(false);
+        }
+        writer.finish(true)
+    }
+}
+
+impl<G: FastGraph> GraphFormat<G> for Sparse6<G> {
+    fn parse_graph_with_max_order<R: BufRead>(
+        reader: R,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError> {
+        parse_first::<R, G, Self>(reader, max_order)
+    }
+
+    fn write_graph<W: Write>(graph: &G, mut writer: W) -> Result<(), GraphFormatError> {
+        writeln!(writer, "{}", Self::encode(graph))?;
+        Ok(())
+    }
+}
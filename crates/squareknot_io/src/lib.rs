@@ -1,11 +1,58 @@
 pub mod dimacs;
+pub mod dot;
+pub mod error;
+pub mod graph6;
 mod pair_iter;
+mod tokens;
+
+pub use error::{GraphFormatError, Position};
 
 use std::io::{BufRead, Write};
 
-use squareknot_graph::AbstractGraph;
+use squareknot_graph::{AbstractGraph, FastGraph};
+
+/// The largest number of vertices accepted by [`GraphFormat::parse_graph`].
+///
+/// Headers which declare the number of vertices are trusted to allocate the graph,
+/// so this keeps a hostile header from requesting an arbitrarily large allocation.
+pub const DEFAULT_MAX_ORDER: usize = 1 << 24;
 
 pub trait GraphFormat<G: AbstractGraph> {
-    fn parse_graph<R: BufRead>(reader: R) -> Result<G, ()>;
-    fn write_graph<W: Write>(writer: W) -> Result<(), ()>;
+    /// Parses a graph with at most [`DEFAULT_MAX_ORDER`] vertices.
+    fn parse_graph<R: BufRead>(reader: R) -> Result<G, GraphFormatError> {
+        Self::parse_graph_with_max_order(reader, DEFAULT_MAX_ORDER)
+    }
+
+    /// Parses a graph, failing with [`GraphFormatError::OrderTooLarge`] if it has more than `max_order` vertices.
+    fn parse_graph_with_max_order<R: BufRead>(
+        reader: R,
+        max_order: usize,
+    ) -> Result<G, GraphFormatError>;
+
+    fn write_graph<W: Write>(graph: &G, writer: W) -> Result<(), GraphFormatError>;
+}
+
+/// Fails if a graph on `order` vertices, declared at `position`, exceeds `max_order`.
+pub(crate) fn check_order(
+    position: Position,
+    order: usize,
+    max_order: usize,
+) -> Result<usize, GraphFormatError> {
+    if order <= max_order {
+        Ok(order)
+    } else {
+        Err(GraphFormatError::OrderTooLarge {
+            position,
+            order,
+            max_order,
+        })
+    }
+}
+
+/// Collects the edges of `graph`, listing each undirected edge (i.e. a pair of opposite arcs) once.
+pub(crate) fn undirected_edges<G: FastGraph>(graph: &G) -> Vec<(usize, usize)> {
+    graph
+        .edge_iterator()
+        .filter(|&(u, v)| u <= v || !graph.has_edge(v, u))
+        .collect()
 }
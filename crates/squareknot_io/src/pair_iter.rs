@@ -1,12 +1,14 @@
 use std::io::BufRead;
 
-use itertools::Itertools;
+use crate::{error::Position, tokens::LineTokens, GraphFormatError};
 
 /// This is a utility for use in parsers
 /// It handles the common scenario where the remaining lines in a file are pairs of numbers
+/// Blank lines are skipped, and a malformed line produces an error rather than ending iteration
 pub struct PairIterator<B: BufRead> {
     reader: B,
     buffer: String,
+    line: usize,
 }
 
 impl<B: BufRead> PairIterator<B> {
@@ -14,24 +16,42 @@ impl<B: BufRead> PairIterator<B> {
         Self {
             reader,
             buffer: String::with_capacity(16),
+            line: 0,
+        }
+    }
+
+    /// Returns the position at the start of the line following the last line read.
+    pub fn next_position(&self) -> Position {
+        Position {
+            line: self.line + 1,
+            column: 1,
         }
     }
 }
 
 impl<B: BufRead> Iterator for PairIterator<B> {
-    type Item = (usize, usize);
+    /// Each number is paired with its position in the input.
+    type Item = Result<((Position, usize), (Position, usize)), GraphFormatError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.buffer.clear();
-        if let Ok(_) = self.reader.read_line(&mut self.buffer) {
-            self.buffer
-                .split_whitespace()
-                .map(|s| s.parse::<usize>().ok())
-                .take(2)
-                .filter_map(|n| n)
-                .collect_tuple::<(usize, usize)>()
-        } else {
-            None
+        loop {
+            self.buffer.clear();
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => self.line += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+            if self.buffer.trim().is_empty() {
+                continue;
+            }
+
+            let mut tokens = LineTokens::new(&self.buffer, self.line);
+            let pair = tokens.next_usize("a number").and_then(|u| {
+                let v = tokens.next_usize("a number")?;
+                tokens.finish()?;
+                Ok((u, v))
+            });
+            return Some(pair);
         }
     }
 }
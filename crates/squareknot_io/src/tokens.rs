@@ -0,0 +1,78 @@
+use crate::{error::Position, GraphFormatError};
+
+/// This is a utility for use in parsers
+/// It splits a line into whitespace separated tokens while keeping track of their positions
+pub(crate) struct LineTokens<'a> {
+    line: &'a str,
+    number: usize,
+    offset: usize,
+}
+
+impl<'a> LineTokens<'a> {
+    /// `number` is the 1-based line number of `line`.
+    pub(crate) fn new(line: &'a str, number: usize) -> Self {
+        Self {
+            line,
+            number,
+            offset: 0,
+        }
+    }
+
+    /// Returns the position of the byte offset `offset` within the line.
+    fn position_of(&self, offset: usize) -> Position {
+        Position {
+            line: self.number,
+            column: self.line[..offset].chars().count() + 1,
+        }
+    }
+
+    /// Returns the position just past the last token read.
+    pub(crate) fn position(&self) -> Position {
+        self.position_of(self.offset)
+    }
+
+    /// Returns the next token along with its position.
+    pub(crate) fn next_token(&mut self) -> Option<(Position, &'a str)> {
+        let rest = &self.line[self.offset..];
+        let start = self.offset + rest.len() - rest.trim_start().len();
+        if start == self.line.len() {
+            self.offset = start;
+            return None;
+        }
+        let end = self.line[start..]
+            .find(char::is_whitespace)
+            .map_or(self.line.len(), |i| start + i);
+        self.offset = end;
+        Some((self.position_of(start), &self.line[start..end]))
+    }
+
+    /// Reads the next token as a number.
+    pub(crate) fn next_usize(
+        &mut self,
+        expected: &'static str,
+    ) -> Result<(Position, usize), GraphFormatError> {
+        let (position, token) = self.next_token().ok_or(GraphFormatError::MissingToken {
+            position: self.position(),
+            expected,
+        })?;
+        token.parse::<usize>().map(|n| (position, n)).map_err(|_| {
+            GraphFormatError::MalformedToken {
+                position,
+                token: token.to_string(),
+                expected,
+            }
+        })
+    }
+
+    /// Fails if any tokens remain on the line.
+    pub(crate) fn finish(&mut self) -> Result<(), GraphFormatError> {
+        match self.next_token() {
+            Some((position, token)) => Err(GraphFormatError::MalformedToken {
+                position,
+                token: token.to_string(),
+                expected: "end of line",
+            }),
+            None => Ok(()),
+        }
+    }
+}
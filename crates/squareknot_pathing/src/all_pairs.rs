@@ -0,0 +1,110 @@
+use squareknot_traversal::{recycle::BFSResources, TraversalView};
+
+/// The distances from a single source to every vertex label.
+/// Unreachable vertices (and labels which are not in the graph) have distance `usize::MAX`.
+#[derive(Clone, Debug)]
+pub struct DistanceRow {
+    pub source: usize,
+    pub distances: Vec<usize>,
+}
+
+/// An iterator over the rows of the unweighted distance matrix of a graph, one source vertex at a time.
+///
+/// A single set of [`BFSResources`] is recycled between sources, so each row costs one allocation for its distances.
+/// Each vertex is a source once, even if the view repeats it.
+pub struct AllPairsShortestPaths<'a, G: TraversalView> {
+    graph: &'a G,
+    sources: Box<dyn Iterator<Item = usize> + 'a>,
+    resources: Option<BFSResources>,
+}
+
+impl<'a, G: TraversalView> AllPairsShortestPaths<'a, G> {
+    pub fn new(graph: &'a G) -> Self {
+        Self::with_resources(graph, BFSResources::new())
+    }
+
+    /// Creates the iterator with previously extracted resources.
+    pub fn with_resources(graph: &'a G, resources: BFSResources) -> Self {
+        Self {
+            graph,
+            sources: Box::new(graph.distinct_vertices().into_iter()),
+            resources: Some(resources),
+        }
+    }
+
+    /// Extracts [`BFSResources`] which may be recycled into another traversal.
+    pub fn extract_resources(mut self) -> BFSResources {
+        self.resources.take().unwrap()
+    }
+
+    /// Runs a breadth first search from the next source, calling `visit(vertex, depth)` on every vertex reached.
+    /// Returns the source, or `None` once every source has been visited.
+    pub(crate) fn next_with<F: FnMut(usize, usize)>(&mut self, mut visit: F) -> Option<usize> {
+        let source = self.sources.next()?;
+        let mut bfs = self.resources.take().unwrap().bfs(self.graph, source);
+        for node in bfs.by_ref() {
+            visit(node.vertex, node.depth);
+        }
+        self.resources = Some(bfs.extract_resources());
+        Some(source)
+    }
+}
+
+impl<G: TraversalView> Iterator for AllPairsShortestPaths<'_, G> {
+    type Item = DistanceRow;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut distances = vec![usize::MAX; self.graph.num_v_labels()];
+        let source = self.next_with(|v, depth| distances[v] = depth)?;
+        Some(DistanceRow { source, distances })
+    }
+}
+
+/// Computes the eccentricity of every vertex, indexed by label.
+pub(crate) fn eccentricities<G: TraversalView>(graph: &G) -> Vec<usize> {
+    let order = graph.distinct_vertices().len();
+    let mut result = vec![usize::MAX; graph.num_v_labels()];
+    let mut rows = AllPairsShortestPaths::new(graph);
+
+    loop {
+        let mut reached = 0;
+        let mut furthest = 0;
+        let Some(source) = rows.next_with(|_, depth| {
+            reached += 1;
+            furthest = furthest.max(depth);
+        }) else {
+            break;
+        };
+        if reached == order {
+            result[source] = furthest;
+        }
+    }
+
+    result
+}
+
+/// Computes the sum of the distances between all unordered pairs of vertices, or `None` if the graph is disconnected.
+pub(crate) fn wiener_index<G: TraversalView>(graph: &G) -> Option<usize> {
+    let order = graph.distinct_vertices().len();
+    let mut total = 0;
+    let mut rows = AllPairsShortestPaths::new(graph);
+
+    loop {
+        let mut reached = 0;
+        if rows
+            .next_with(|_, depth| {
+                reached += 1;
+                total += depth;
+            })
+            .is_none()
+        {
+            break;
+        }
+        if reached != order {
+            return None;
+        }
+    }
+
+    // Every pair was counted from both ends
+    Some(total / 2)
+}
@@ -0,0 +1,66 @@
+use std::collections::BinaryHeap;
+
+use squareknot_graph::{ExactCombinator, Weight, WeightedViewCombinator};
+
+use crate::dijkstra::{walk_predecessors, HeapEntry, WeightedPath};
+
+/// Runs A* from `source` to `target`, guided by `heuristic`.
+///
+/// The result is a shortest path whenever `heuristic` never overestimates the remaining distance to `target`.
+/// Vertices are re-expanded when a cheaper route to them is found, so the heuristic need not be consistent.
+pub(crate) fn astar<G, H>(
+    graph: &G,
+    source: usize,
+    target: usize,
+    heuristic: H,
+) -> Option<WeightedPath<G::Weight>>
+where
+    G: WeightedViewCombinator<VertexLabel = usize> + ExactCombinator,
+    H: Fn(usize) -> G::Weight,
+{
+    let n = graph.num_v_labels();
+    let mut distances: Vec<Option<G::Weight>> = vec![None; n];
+    let mut predecessors = vec![usize::MAX; n];
+    let mut heap = BinaryHeap::new();
+
+    distances[source] = Some(G::Weight::zero());
+    predecessors[source] = source;
+    heap.push(HeapEntry {
+        key: heuristic(source),
+        vertex: source,
+    });
+
+    while let Some(HeapEntry { key, vertex }) = heap.pop() {
+        let distance = distances[vertex].unwrap();
+
+        // Skip entries which have been superseded by a cheaper route
+        if distance + heuristic(vertex) < key {
+            continue;
+        }
+
+        if vertex == target {
+            return Some(WeightedPath {
+                vertices: walk_predecessors(&predecessors, target),
+                cost: distance,
+            });
+        }
+
+        let Some(neighbors) = graph.weighted_neighbor_iterator(vertex) else {
+            continue;
+        };
+
+        for (neighbor, weight) in neighbors {
+            let candidate = distance + weight;
+            if distances[neighbor].is_none_or(|d| candidate < d) {
+                distances[neighbor] = Some(candidate);
+                predecessors[neighbor] = vertex;
+                heap.push(HeapEntry {
+                    key: candidate + heuristic(neighbor),
+                    vertex: neighbor,
+                });
+            }
+        }
+    }
+
+    None
+}
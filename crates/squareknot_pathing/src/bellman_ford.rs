@@ -0,0 +1,93 @@
+use std::{error::Error, fmt::Display};
+
+use squareknot_graph::{ExactCombinator, Weight, WeightedViewCombinator};
+
+use crate::ShortestPathTree;
+
+/// A cycle of negative total weight, reported by algorithms which cannot handle one.
+///
+/// `cycle` lists the vertices of the cycle in the order in which its edges are traversed,
+/// with the edge from the last vertex back to the first closing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegativeCycle {
+    pub cycle: Vec<usize>,
+}
+
+impl Display for NegativeCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "graph contains a negative cycle through {:?}",
+            self.cycle
+        )
+    }
+}
+
+impl Error for NegativeCycle {}
+
+/// Runs the Bellman-Ford algorithm.
+///
+/// If `source` is `None`, every vertex starts at distance zero, which is equivalent to adding a virtual source with a zero weight edge to every vertex.
+/// The resulting tree then has `source == usize::MAX`.
+pub(crate) fn bellman_ford<G: WeightedViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+    source: Option<usize>,
+) -> Result<ShortestPathTree<G::Weight>, NegativeCycle> {
+    let n = graph.num_v_labels();
+    let mut distances = vec![None; n];
+    let mut predecessors = vec![usize::MAX; n];
+
+    match source {
+        Some(s) => {
+            distances[s] = Some(G::Weight::zero());
+            predecessors[s] = s;
+        }
+        None => {
+            for v in graph.vertex_iterator() {
+                distances[v] = Some(G::Weight::zero());
+                predecessors[v] = v;
+            }
+        }
+    }
+
+    // After `n` rounds without convergence, the last relaxed vertex is reachable from a negative cycle
+    let mut last_relaxed = None;
+    for _ in 0..n {
+        last_relaxed = None;
+        for (u, v, w) in graph.weighted_edge_iterator() {
+            let Some(du) = distances[u] else {
+                continue;
+            };
+            let candidate = du + w;
+            if distances[v].is_none_or(|dv| candidate < dv) {
+                distances[v] = Some(candidate);
+                predecessors[v] = u;
+                last_relaxed = Some(v);
+            }
+        }
+        if last_relaxed.is_none() {
+            break;
+        }
+    }
+
+    if let Some(mut v) = last_relaxed {
+        // Walking back `n` predecessors is guaranteed to land on the cycle
+        for _ in 0..n {
+            v = predecessors[v];
+        }
+        let mut cycle = vec![v];
+        let mut current = predecessors[v];
+        while current != v {
+            cycle.push(current);
+            current = predecessors[current];
+        }
+        cycle.reverse();
+        return Err(NegativeCycle { cycle });
+    }
+
+    Ok(ShortestPathTree {
+        source: source.unwrap_or(usize::MAX),
+        distances,
+        predecessors,
+    })
+}
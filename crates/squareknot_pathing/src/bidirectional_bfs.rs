@@ -0,0 +1,151 @@
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+/// The number of shortest paths between two vertices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShortestPathCount {
+    pub distance: usize,
+    /// The number of distinct shortest paths, saturating at `u128::MAX`.
+    pub count: u128,
+}
+
+/// One side of a bidirectional breadth first search, which is expanded one full level at a time.
+struct Side {
+    distances: Vec<usize>,
+    parents: Vec<usize>,
+    /// The number of shortest paths from the root to each vertex. Only maintained when counting.
+    counts: Vec<u128>,
+    frontier: Vec<usize>,
+    next: Vec<usize>,
+}
+
+impl Side {
+    fn new(n: usize, root: usize, counting: bool) -> Self {
+        let mut distances = vec![usize::MAX; n];
+        let mut parents = vec![usize::MAX; n];
+        distances[root] = 0;
+        parents[root] = root;
+        let mut counts = if counting { vec![0; n] } else { vec![] };
+        if counting {
+            counts[root] = 1;
+        }
+        Self {
+            distances,
+            parents,
+            counts,
+            frontier: vec![root],
+            next: vec![],
+        }
+    }
+
+    /// Expands the frontier by one level, returning the newly reached vertices which `other` has also reached.
+    fn expand<G: ViewCombinator<VertexLabel = usize>>(
+        &mut self,
+        graph: &G,
+        other: &Side,
+    ) -> Vec<usize> {
+        let counting = !self.counts.is_empty();
+        let mut meeting = vec![];
+        for &u in &self.frontier {
+            let depth = self.distances[u] + 1;
+            for w in graph.neighbor_iterator(u).into_iter().flatten() {
+                if self.distances[w] == usize::MAX {
+                    self.distances[w] = depth;
+                    self.parents[w] = u;
+                    self.next.push(w);
+                    if other.distances[w] != usize::MAX {
+                        meeting.push(w);
+                    }
+                }
+                if counting && self.distances[w] == depth {
+                    self.counts[w] = self.counts[w].saturating_add(self.counts[u]);
+                }
+            }
+        }
+        std::mem::swap(&mut self.frontier, &mut self.next);
+        self.next.clear();
+        meeting
+    }
+
+    /// Lists the vertices from `vertex` back to the root.
+    fn walk(&self, mut vertex: usize) -> Vec<usize> {
+        let mut path = vec![vertex];
+        while self.parents[vertex] != vertex {
+            vertex = self.parents[vertex];
+            path.push(vertex);
+        }
+        path
+    }
+}
+
+/// Runs breadth first searches from `source` over `graph` and from `target` over `reverse`, always expanding the
+/// smaller frontier by a full level, until they meet.
+///
+/// Returns both sides along with the vertices at which they first met, all of which lie on shortest paths.
+/// Each shortest path passes through exactly one of them.
+fn search<G, R>(
+    graph: &G,
+    reverse: &R,
+    source: usize,
+    target: usize,
+    counting: bool,
+) -> Option<(Side, Side, Vec<usize>)>
+where
+    G: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+    R: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+{
+    let n = graph.num_v_labels();
+    let mut forward = Side::new(n, source, counting);
+    let mut backward = Side::new(n, target, counting);
+    if source == target {
+        return Some((forward, backward, vec![source]));
+    }
+
+    while !forward.frontier.is_empty() && !backward.frontier.is_empty() {
+        let meeting = if forward.frontier.len() <= backward.frontier.len() {
+            forward.expand(graph, &backward)
+        } else {
+            backward.expand(reverse, &forward)
+        };
+        if !meeting.is_empty() {
+            return Some((forward, backward, meeting));
+        }
+    }
+    None
+}
+
+pub(crate) fn bidirectional_bfs<G, R>(
+    graph: &G,
+    reverse: &R,
+    source: usize,
+    target: usize,
+) -> Option<Vec<usize>>
+where
+    G: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+    R: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+{
+    let (forward, backward, meeting) = search(graph, reverse, source, target, false)?;
+    let mut path = forward.walk(meeting[0]);
+    path.reverse();
+    path.extend(backward.walk(meeting[0]).into_iter().skip(1));
+    Some(path)
+}
+
+/// Counts shortest paths by summing, over the vertices at which the searches met, the products of the number of
+/// shortest paths reaching them from each side.
+pub(crate) fn count_shortest_paths<G, R>(
+    graph: &G,
+    reverse: &R,
+    source: usize,
+    target: usize,
+) -> Option<ShortestPathCount>
+where
+    G: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+    R: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+{
+    let (forward, backward, meeting) = search(graph, reverse, source, target, true)?;
+    let distance = forward.distances[meeting[0]] + backward.distances[meeting[0]];
+    let count = meeting.iter().fold(0u128, |total, &w| {
+        total.saturating_add(forward.counts[w].saturating_mul(backward.counts[w]))
+    });
+    Some(ShortestPathCount { distance, count })
+}
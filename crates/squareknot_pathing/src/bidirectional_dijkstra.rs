@@ -0,0 +1,131 @@
+use std::collections::BinaryHeap;
+
+use squareknot_graph::{ExactCombinator, Weight, WeightedViewCombinator};
+
+use crate::dijkstra::{walk_predecessors, HeapEntry, WeightedPath};
+
+/// The state of one direction of a bidirectional search.
+struct Frontier<W: Weight> {
+    distances: Vec<Option<W>>,
+    predecessors: Vec<usize>,
+    settled: Vec<bool>,
+    heap: BinaryHeap<HeapEntry<W>>,
+}
+
+impl<W: Weight> Frontier<W> {
+    fn new(n: usize, root: usize) -> Self {
+        let mut distances = vec![None; n];
+        let mut predecessors = vec![usize::MAX; n];
+        distances[root] = Some(W::zero());
+        predecessors[root] = root;
+
+        Self {
+            distances,
+            predecessors,
+            settled: vec![false; n],
+            heap: BinaryHeap::from(vec![HeapEntry {
+                key: W::zero(),
+                vertex: root,
+            }]),
+        }
+    }
+
+    /// Discards stale heap entries and returns the smallest tentative distance.
+    fn peek(&mut self) -> Option<W> {
+        while let Some(entry) = self.heap.peek() {
+            if self.settled[entry.vertex] {
+                self.heap.pop();
+            } else {
+                return Some(entry.key);
+            }
+        }
+        None
+    }
+
+    /// Settles the closest vertex and relaxes its edges.
+    /// Updates `best` whenever an edge connects to a vertex reached by `other`.
+    fn step<G: WeightedViewCombinator<VertexLabel = usize, Weight = W>>(
+        &mut self,
+        graph: &G,
+        other: &Frontier<W>,
+        best: &mut Option<(W, usize, usize)>,
+    ) {
+        let Some(HeapEntry { key, vertex }) = self.heap.pop() else {
+            return;
+        };
+        self.settled[vertex] = true;
+
+        let Some(neighbors) = graph.weighted_neighbor_iterator(vertex) else {
+            return;
+        };
+
+        for (neighbor, weight) in neighbors {
+            let candidate = key + weight;
+            if !self.settled[neighbor] && self.distances[neighbor].is_none_or(|d| candidate < d) {
+                self.distances[neighbor] = Some(candidate);
+                self.predecessors[neighbor] = vertex;
+                self.heap.push(HeapEntry {
+                    key: candidate,
+                    vertex: neighbor,
+                });
+            }
+            if let Some(remaining) = other.distances[neighbor] {
+                let total = candidate + remaining;
+                if best.is_none_or(|(b, _, _)| total < b) {
+                    *best = Some((total, vertex, neighbor));
+                }
+            }
+        }
+    }
+}
+
+/// Runs Dijkstra's algorithm simultaneously forwards from `source` over `forward` and backwards from `target` over `backward`.
+///
+/// `backward` must contain the reverse of every edge of `forward`. For undirected graphs, both may be the same view.
+pub(crate) fn bidirectional_dijkstra<F, B>(
+    forward: &F,
+    backward: &B,
+    source: usize,
+    target: usize,
+) -> Option<WeightedPath<F::Weight>>
+where
+    F: WeightedViewCombinator<VertexLabel = usize> + ExactCombinator,
+    B: WeightedViewCombinator<VertexLabel = usize, Weight = F::Weight> + ExactCombinator,
+{
+    if source == target {
+        return Some(WeightedPath {
+            vertices: vec![source],
+            cost: F::Weight::zero(),
+        });
+    }
+
+    let n = forward.num_v_labels().max(backward.num_v_labels());
+    let mut fwd = Frontier::new(n, source);
+    let mut bwd = Frontier::new(n, target);
+
+    // The best path found so far, as (cost, tail, head) where (tail, head) is the edge joining the two searches
+    let mut best: Option<(F::Weight, usize, usize)> = None;
+
+    while let (Some(f), Some(b)) = (fwd.peek(), bwd.peek()) {
+        // No undiscovered path can be shorter than the sum of the two frontiers
+        if best.is_some_and(|(cost, _, _)| f + b >= cost) {
+            break;
+        }
+
+        if f <= b {
+            fwd.step(forward, &bwd, &mut best);
+        } else {
+            let mut reversed = best.map(|(cost, tail, head)| (cost, head, tail));
+            bwd.step(backward, &fwd, &mut reversed);
+            best = reversed.map(|(cost, head, tail)| (cost, tail, head));
+        }
+    }
+
+    let (cost, tail, head) = best?;
+    let mut vertices = walk_predecessors(&fwd.predecessors, tail);
+    let mut rest = walk_predecessors(&bwd.predecessors, head);
+    rest.reverse();
+    vertices.extend(rest);
+
+    Some(WeightedPath { vertices, cost })
+}
@@ -0,0 +1,140 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use squareknot_graph::{ExactCombinator, Weight, WeightedViewCombinator};
+
+/// A path through a weighted graph together with its total cost.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedPath<W: Weight> {
+    /// The vertices of the path, starting at the source and ending at the target.
+    pub vertices: Vec<usize>,
+    /// The sum of the weights of the edges along the path.
+    pub cost: W,
+}
+
+/// The result of a single source shortest path search.
+///
+/// `predecessors[v]` is the vertex preceding `v` on a shortest path from the source, or `usize::MAX` if `v` was not reached.
+/// The source is its own predecessor.
+#[derive(Clone, Debug)]
+pub struct ShortestPathTree<W: Weight> {
+    pub source: usize,
+    pub distances: Vec<Option<W>>,
+    pub predecessors: Vec<usize>,
+}
+
+impl<W: Weight> ShortestPathTree<W> {
+    /// Returns the distance from the source to `target`, or `None` if it was not reached.
+    pub fn distance(&self, target: usize) -> Option<W> {
+        self.distances.get(target).copied().flatten()
+    }
+
+    /// Reconstructs a shortest path from the source to `target`, or returns `None` if it was not reached.
+    pub fn path_to(&self, target: usize) -> Option<WeightedPath<W>> {
+        let cost = self.distance(target)?;
+        Some(WeightedPath {
+            vertices: walk_predecessors(&self.predecessors, target),
+            cost,
+        })
+    }
+}
+
+/// Follows predecessor links from `target` back to a root, and returns the path from the root to `target`.
+pub(crate) fn walk_predecessors(predecessors: &[usize], target: usize) -> Vec<usize> {
+    let mut path = vec![target];
+    let mut current = target;
+    while predecessors[current] != current {
+        current = predecessors[current];
+        path.push(current);
+    }
+    path.reverse();
+    path
+}
+
+/// An entry of a min-heap keyed by `key`.
+/// Weights are only partially ordered, so incomparable keys are treated as equal.
+pub(crate) struct HeapEntry<W: Weight> {
+    pub(crate) key: W,
+    pub(crate) vertex: usize,
+}
+
+impl<W: Weight> PartialEq for HeapEntry<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: Weight> Eq for HeapEntry<W> {}
+
+impl<W: Weight> PartialOrd for HeapEntry<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Weight> Ord for HeapEntry<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, since `BinaryHeap` is a max-heap
+        other
+            .key
+            .partial_cmp(&self.key)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| other.vertex.cmp(&self.vertex))
+    }
+}
+
+/// Runs Dijkstra's algorithm from `source`, stopping early once `target` is settled.
+/// Edge weights are assumed to be non-negative.
+pub(crate) fn dijkstra_search<G: WeightedViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+    source: usize,
+    target: Option<usize>,
+) -> ShortestPathTree<G::Weight> {
+    let n = graph.num_v_labels();
+    let mut distances = vec![None; n];
+    let mut predecessors = vec![usize::MAX; n];
+    let mut settled = vec![false; n];
+    let mut heap = BinaryHeap::new();
+
+    distances[source] = Some(G::Weight::zero());
+    predecessors[source] = source;
+    heap.push(HeapEntry {
+        key: G::Weight::zero(),
+        vertex: source,
+    });
+
+    while let Some(HeapEntry { key, vertex }) = heap.pop() {
+        if settled[vertex] {
+            continue;
+        }
+        settled[vertex] = true;
+
+        if target == Some(vertex) {
+            break;
+        }
+
+        let Some(neighbors) = graph.weighted_neighbor_iterator(vertex) else {
+            continue;
+        };
+
+        for (neighbor, weight) in neighbors {
+            if settled[neighbor] {
+                continue;
+            }
+            let candidate = key + weight;
+            if distances[neighbor].is_none_or(|d| candidate < d) {
+                distances[neighbor] = Some(candidate);
+                predecessors[neighbor] = vertex;
+                heap.push(HeapEntry {
+                    key: candidate,
+                    vertex: neighbor,
+                });
+            }
+        }
+    }
+
+    ShortestPathTree {
+        source,
+        distances,
+        predecessors,
+    }
+}
@@ -0,0 +1,95 @@
+use std::ops::Sub;
+
+use squareknot_graph::{ExactCombinator, ViewCombinator, Weight, WeightedViewCombinator};
+
+use crate::{
+    bellman_ford::bellman_ford, dijkstra::dijkstra_search, NegativeCycle, ShortestPathTree,
+};
+
+/// A view of a weighted graph whose edge weights are shifted by a potential, so that `w'(u, v) = w(u, v) + h(u) - h(v)`.
+struct Reweighted<'g, G: WeightedViewCombinator<VertexLabel = usize>> {
+    graph: &'g G,
+    potential: Vec<G::Weight>,
+}
+
+impl<G: WeightedViewCombinator<VertexLabel = usize>> ViewCombinator for Reweighted<'_, G> {
+    type VertexLabel = usize;
+
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = Self::VertexLabel> + 'a {
+        self.graph.vertex_iterator()
+    }
+
+    fn edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel)> + 'a {
+        self.graph.edge_iterator()
+    }
+
+    fn neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = Self::VertexLabel> + 'a> {
+        self.graph.neighbor_iterator(vertex)
+    }
+}
+
+impl<G: WeightedViewCombinator<VertexLabel = usize> + ExactCombinator> ExactCombinator
+    for Reweighted<'_, G>
+{
+    fn num_v_labels(&self) -> usize {
+        self.graph.num_v_labels()
+    }
+}
+
+impl<G: WeightedViewCombinator<VertexLabel = usize>> WeightedViewCombinator for Reweighted<'_, G>
+where
+    G::Weight: Sub<Output = G::Weight>,
+{
+    type Weight = G::Weight;
+
+    fn weighted_edge_iterator<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Self::VertexLabel, Self::VertexLabel, Self::Weight)> + 'a {
+        self.graph
+            .weighted_edge_iterator()
+            .map(|(u, v, w)| (u, v, w + self.potential[u] - self.potential[v]))
+    }
+
+    fn weighted_neighbor_iterator<'a>(
+        &'a self,
+        vertex: Self::VertexLabel,
+    ) -> Option<impl Iterator<Item = (Self::VertexLabel, Self::Weight)> + 'a> {
+        let hu = self.potential[vertex];
+        self.graph
+            .weighted_neighbor_iterator(vertex)
+            .map(move |neigh| neigh.map(move |(v, w)| (v, w + hu - self.potential[v])))
+    }
+}
+
+/// Computes shortest paths between every pair of vertices using Johnson's algorithm.
+/// The tree at index `s` of the result holds the shortest paths from `s`.
+pub(crate) fn johnson<G: WeightedViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+) -> Result<Vec<ShortestPathTree<G::Weight>>, NegativeCycle>
+where
+    G::Weight: Sub<Output = G::Weight>,
+{
+    let potential = bellman_ford(graph, None)?
+        .distances
+        .into_iter()
+        .map(|d| d.unwrap_or(G::Weight::zero()))
+        .collect::<Vec<_>>();
+
+    let reweighted = Reweighted { graph, potential };
+
+    Ok((0..graph.num_v_labels())
+        .map(|s| {
+            let mut tree = dijkstra_search(&reweighted, s, None);
+            let hs = reweighted.potential[s];
+            for (v, d) in tree.distances.iter_mut().enumerate() {
+                *d = d.map(|d| d + reweighted.potential[v] - hs);
+            }
+            tree
+        })
+        .collect())
+}
@@ -1,27 +1,192 @@
 //! This crate contains functionality related to graph paths.
 
+pub mod all_pairs;
+pub mod astar;
+pub mod bellman_ford;
+pub mod bidirectional_bfs;
+pub mod bidirectional_dijkstra;
+pub mod dijkstra;
+pub mod johnson;
+pub use all_pairs::{AllPairsShortestPaths, DistanceRow};
+pub use bellman_ford::NegativeCycle;
+pub use bidirectional_bfs::ShortestPathCount;
+pub use dijkstra::{ShortestPathTree, WeightedPath};
+
+use std::ops::Sub;
+
+use squareknot_graph::{ExactCombinator, WeightedViewCombinator};
 use squareknot_traversal::TraversalView;
 
+#[allow(clippy::result_unit_err)]
 pub trait PathingGraph: TraversalView {
     fn shortest_path(graph: &Self, u: usize, v: usize) -> Result<Vec<usize>, ()>;
+
+    /// Computes a shortest path from `source` to `target` by searching from both ends at once.
+    /// The backwards search runs over `reverse`, which must contain the reverse of every edge
+    /// (e.g. a [`squareknot_graph::TransposeView`]). An undirected view is its own reverse.
+    fn bidirectional_bfs<R: TraversalView>(
+        &self,
+        reverse: &R,
+        source: usize,
+        target: usize,
+    ) -> Option<Vec<usize>> {
+        bidirectional_bfs::bidirectional_bfs(self, reverse, source, target)
+    }
+
+    /// Counts the shortest paths from `source` to `target`, with the backwards search running over `reverse`
+    /// as in [`Self::bidirectional_bfs`]. Repeated edges count as distinct paths.
+    fn count_shortest_paths<R: TraversalView>(
+        &self,
+        reverse: &R,
+        source: usize,
+        target: usize,
+    ) -> Option<ShortestPathCount> {
+        bidirectional_bfs::count_shortest_paths(self, reverse, source, target)
+    }
+
+    /// Iterates over the rows of the unweighted distance matrix, one source vertex at a time.
+    fn all_pairs_shortest_paths(&self) -> AllPairsShortestPaths<'_, Self> {
+        AllPairsShortestPaths::new(self)
+    }
+
+    /// Computes the eccentricity of every vertex, indexed by label.
+    /// Vertices which cannot reach every other vertex, and labels which are not in the graph, have eccentricity `usize::MAX`.
+    fn eccentricities(&self) -> Vec<usize> {
+        all_pairs::eccentricities(self)
+    }
+
+    /// Returns the minimum eccentricity, or `None` if the graph is empty or disconnected.
+    fn radius(&self) -> Option<usize> {
+        let ecc = self.eccentricities();
+        self.vertex_iterator()
+            .map(|v| ecc[v])
+            .min()
+            .filter(|&r| r != usize::MAX)
+    }
+
+    /// Returns the maximum eccentricity, or `None` if the graph is empty or disconnected.
+    fn diameter(&self) -> Option<usize> {
+        let ecc = self.eccentricities();
+        self.vertex_iterator()
+            .map(|v| ecc[v])
+            .max()
+            .filter(|&d| d != usize::MAX)
+    }
+
+    /// Returns the vertices of minimum eccentricity, or nothing if the graph is disconnected.
+    fn center(&self) -> Vec<usize> {
+        let ecc = self.eccentricities();
+        match self.vertex_iterator().map(|v| ecc[v]).min() {
+            Some(r) if r != usize::MAX => self.vertex_iterator().filter(|&v| ecc[v] == r).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Returns the vertices of maximum eccentricity, or nothing if the graph is disconnected.
+    fn periphery(&self) -> Vec<usize> {
+        let ecc = self.eccentricities();
+        match self.vertex_iterator().map(|v| ecc[v]).max() {
+            Some(d) if d != usize::MAX => self.vertex_iterator().filter(|&v| ecc[v] == d).collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Returns the sum of distances between all unordered pairs of vertices of an undirected graph,
+    /// or `None` if the graph is disconnected.
+    fn wiener_index(&self) -> Option<usize> {
+        all_pairs::wiener_index(self)
+    }
 }
 
 impl<G: TraversalView> PathingGraph for G {
-    // TODO: This is no longer the best way to do this
     fn shortest_path(graph: &Self, u: usize, v: usize) -> Result<Vec<usize>, ()> {
-        let mut stack = vec![];
-        let bfs = graph.bfs(u);
-        for n in bfs {
-            if n.depth < stack.len() {
-                stack[n.depth] = n.vertex;
-            } else {
-                stack.push(n.vertex);
-            }
+        let mut parents = vec![usize::MAX; graph.num_v_labels()];
+        for n in graph.bfs(u) {
+            parents[n.vertex] = n.parent;
             if n.vertex == v {
-                stack.resize(n.depth + 1, usize::MAX);
-                return Ok(stack);
+                let mut path = vec![v];
+                let mut vertex = v;
+                while vertex != u {
+                    vertex = parents[vertex];
+                    path.push(vertex);
+                }
+                path.reverse();
+                return Ok(path);
             }
         }
         Err(())
     }
 }
+
+/// Trait implementing shortest path methods on weighted graphs with `usize` vertices
+///
+/// Unless stated otherwise, these methods assume that edge weights are non-negative.
+pub trait WeightedPathingGraph:
+    WeightedViewCombinator<VertexLabel = usize> + ExactCombinator
+{
+    /// Computes shortest paths from `source` to every reachable vertex using Dijkstra's algorithm.
+    fn dijkstra(&self, source: usize) -> ShortestPathTree<Self::Weight> {
+        dijkstra::dijkstra_search(self, source, None)
+    }
+
+    /// Computes a shortest path from `source` to `target` using Dijkstra's algorithm.
+    /// The search stops as soon as `target` is reached.
+    fn dijkstra_path(&self, source: usize, target: usize) -> Option<WeightedPath<Self::Weight>> {
+        dijkstra::dijkstra_search(self, source, Some(target)).path_to(target)
+    }
+
+    /// Computes a shortest path from `source` to `target` using A*.
+    /// `heuristic(v)` should estimate the distance from `v` to `target` without overestimating it.
+    fn astar<H: Fn(usize) -> Self::Weight>(
+        &self,
+        source: usize,
+        target: usize,
+        heuristic: H,
+    ) -> Option<WeightedPath<Self::Weight>> {
+        astar::astar(self, source, target, heuristic)
+    }
+
+    /// Computes a shortest path from `source` to `target` by searching from both ends at once.
+    /// The view is searched in both directions, so it should be undirected. See [`Self::bidirectional_dijkstra_with`] otherwise.
+    fn bidirectional_dijkstra(
+        &self,
+        source: usize,
+        target: usize,
+    ) -> Option<WeightedPath<Self::Weight>> {
+        bidirectional_dijkstra::bidirectional_dijkstra(self, self, source, target)
+    }
+
+    /// Computes a shortest path from `source` to `target` by searching from both ends at once.
+    /// The backwards search runs over `reverse`, which should contain the reverse of every edge (e.g. a [`squareknot_graph::TransposeView`]).
+    fn bidirectional_dijkstra_with<R>(
+        &self,
+        reverse: &R,
+        source: usize,
+        target: usize,
+    ) -> Option<WeightedPath<Self::Weight>>
+    where
+        R: WeightedViewCombinator<VertexLabel = usize, Weight = Self::Weight> + ExactCombinator,
+    {
+        bidirectional_dijkstra::bidirectional_dijkstra(self, reverse, source, target)
+    }
+
+    /// Computes shortest paths from `source` to every reachable vertex using the Bellman-Ford algorithm.
+    /// Negative weights are allowed. If a negative cycle is reachable from `source`, it is returned instead.
+    fn bellman_ford(&self, source: usize) -> Result<ShortestPathTree<Self::Weight>, NegativeCycle> {
+        bellman_ford::bellman_ford(self, Some(source))
+    }
+
+    /// Computes shortest paths between every pair of vertices using Johnson's algorithm.
+    /// The tree at index `s` of the result holds the shortest paths from `s`.
+    ///
+    /// Negative weights are allowed. If the graph contains a negative cycle, it is returned instead.
+    /// Note that in an undirected view a negative edge is itself a negative cycle.
+    fn johnson(&self) -> Result<Vec<ShortestPathTree<Self::Weight>>, NegativeCycle>
+    where
+        Self::Weight: Sub<Output = Self::Weight>,
+    {
+        johnson::johnson(self)
+    }
+}
+
+impl<G: WeightedViewCombinator<VertexLabel = usize> + ExactCombinator> WeightedPathingGraph for G {}
@@ -0,0 +1,219 @@
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+/// The results of a lowpoint search over an undirected graph.
+///
+/// The graph is treated as simple: repeated edges never prevent a bridge, but a repeated non-tree edge
+/// is listed in its block once per repetition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Biconnectivity {
+    /// Vertices whose removal increases the number of connected components.
+    pub articulation_points: Vec<usize>,
+    /// Edges whose removal increases the number of connected components, as `(parent, child)` in the search tree.
+    pub bridges: Vec<(usize, usize)>,
+    /// The biconnected components, as a partition of the edges. Each edge is listed once, in one direction.
+    pub blocks: Vec<Vec<(usize, usize)>>,
+    /// The 2-edge-connected components, as a partition of the vertices.
+    pub two_edge_connected_components: Vec<Vec<usize>>,
+}
+
+/// Runs an iterative depth first search which tracks lowpoint values, i.e. the earliest discovery time reachable
+/// from a subtree using at most one back edge.
+pub(crate) fn lowpoint_search<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+) -> Biconnectivity {
+    let n = graph.num_v_labels();
+    let mut discovered = vec![usize::MAX; n];
+    let mut low = vec![usize::MAX; n];
+    let mut is_articulation = vec![false; n];
+    let mut time = 0;
+
+    let mut bridges = vec![];
+    let mut blocks = vec![];
+    let mut two_edge_connected_components = vec![];
+    let mut edge_stack = vec![];
+    let mut vertex_stack = vec![];
+
+    for root in graph.vertex_iterator() {
+        if discovered[root] != usize::MAX {
+            continue;
+        }
+        discovered[root] = time;
+        low[root] = time;
+        time += 1;
+        vertex_stack.push(root);
+        let mut root_children = 0;
+        let mut frames = vec![(root, usize::MAX, graph.neighbor_iterator(root).unwrap())];
+
+        while let Some((v, parent, neighbors)) = frames.last_mut() {
+            let (v, parent) = (*v, *parent);
+            if let Some(w) = neighbors.next() {
+                if w == parent {
+                    continue;
+                }
+                if discovered[w] == usize::MAX {
+                    discovered[w] = time;
+                    low[w] = time;
+                    time += 1;
+                    if v == root {
+                        root_children += 1;
+                    }
+                    edge_stack.push((v, w));
+                    vertex_stack.push(w);
+                    frames.push((w, v, graph.neighbor_iterator(w).unwrap()));
+                } else if discovered[w] < discovered[v] {
+                    // A back edge, seen from its lower end. It is seen again from `w`, where it is ignored.
+                    edge_stack.push((v, w));
+                    low[v] = low[v].min(discovered[w]);
+                }
+                continue;
+            }
+
+            frames.pop();
+            if low[v] == discovered[v] {
+                let mut component = vec![];
+                loop {
+                    let u = vertex_stack.pop().unwrap();
+                    component.push(u);
+                    if u == v {
+                        break;
+                    }
+                }
+                two_edge_connected_components.push(component);
+            }
+            if parent == usize::MAX {
+                continue;
+            }
+
+            low[parent] = low[parent].min(low[v]);
+            if low[v] > discovered[parent] {
+                bridges.push((parent, v));
+            }
+            if low[v] >= discovered[parent] {
+                if parent != root {
+                    is_articulation[parent] = true;
+                }
+                let mut block = vec![];
+                loop {
+                    let edge = edge_stack.pop().unwrap();
+                    block.push(edge);
+                    if edge == (parent, v) {
+                        break;
+                    }
+                }
+                blocks.push(block);
+            }
+        }
+        if root_children > 1 {
+            is_articulation[root] = true;
+        }
+    }
+
+    Biconnectivity {
+        articulation_points: (0..n).filter(|&v| is_articulation[v]).collect(),
+        bridges,
+        blocks,
+        two_edge_connected_components,
+    }
+}
+
+/// The block-cut tree (or forest) of an undirected graph.
+///
+/// Nodes `0..blocks.len()` are blocks, and node `blocks.len() + i` is `cut_vertices[i]`.
+/// A block is adjacent to each cut vertex it contains.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockCutTree {
+    /// The vertices of each block. Isolated vertices form blocks on their own.
+    pub blocks: Vec<Vec<usize>>,
+    pub cut_vertices: Vec<usize>,
+    /// The node containing each vertex: its cut vertex node if it is a cut vertex, and its block otherwise.
+    /// Labels not in the graph map to `usize::MAX`.
+    pub node_of: Vec<usize>,
+    pub(crate) adjacency: Vec<Vec<usize>>,
+}
+
+impl BlockCutTree {
+    pub(crate) fn build<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+        graph: &G,
+        biconnectivity: Biconnectivity,
+    ) -> Self {
+        let n = graph.num_v_labels();
+        let mut node_of = vec![usize::MAX; n];
+        let mut blocks = vec![];
+        // The last block which listed each vertex, to skip vertices shared by several edges of a block
+        let mut listed_by = vec![usize::MAX; n];
+        for edges in &biconnectivity.blocks {
+            let b = blocks.len();
+            let mut block = vec![];
+            for &(u, v) in edges {
+                for w in [u, v] {
+                    if listed_by[w] != b {
+                        listed_by[w] = b;
+                        node_of[w] = b;
+                        block.push(w);
+                    }
+                }
+            }
+            blocks.push(block);
+        }
+        for v in graph.vertex_iterator() {
+            if node_of[v] == usize::MAX {
+                node_of[v] = blocks.len();
+                blocks.push(vec![v]);
+            }
+        }
+
+        let cut_vertices = biconnectivity.articulation_points;
+        let mut adjacency = vec![vec![]; blocks.len() + cut_vertices.len()];
+        for (i, &c) in cut_vertices.iter().enumerate() {
+            node_of[c] = blocks.len() + i;
+        }
+        for (b, block) in blocks.iter().enumerate() {
+            for &v in block {
+                let node = node_of[v];
+                if node >= blocks.len() {
+                    adjacency[b].push(node);
+                    adjacency[node].push(b);
+                }
+            }
+        }
+
+        Self {
+            blocks,
+            cut_vertices,
+            node_of,
+            adjacency,
+        }
+    }
+
+    /// Returns `true` if `node` is a block, and `false` if it is a cut vertex.
+    pub fn is_block(&self, node: usize) -> bool {
+        node < self.blocks.len()
+    }
+}
+
+impl ViewCombinator for BlockCutTree {
+    type VertexLabel = usize;
+
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        0..self.adjacency.len()
+    }
+
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(u, neigh)| neigh.iter().map(move |&v| (u, v)))
+    }
+
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> Option<impl Iterator<Item = usize> + 'a> {
+        self.adjacency
+            .get(vertex)
+            .map(|neigh| neigh.iter().copied())
+    }
+}
+
+impl ExactCombinator for BlockCutTree {
+    fn num_v_labels(&self) -> usize {
+        self.adjacency.len()
+    }
+}
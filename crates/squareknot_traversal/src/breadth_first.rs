@@ -8,6 +8,7 @@ pub struct BFSTraversal<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombin
     pub(crate) graph: &'a G,
     pub(crate) parents: Vec<usize>,
     pub(crate) queue: VecDeque<TraversalNode>,
+    pub(crate) max_depth: usize,
 }
 
 impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> BFSTraversal<'a, G> {
@@ -23,14 +24,22 @@ impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> BFSTraversal<
                 depth: 0,
                 parent: root,
             }]),
+            max_depth: usize::MAX,
         }
     }
 
+    /// Stops the traversal from enqueueing vertices deeper than `max_depth`.
+    pub fn limit_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     /// Extracts [`BFSResources`] which may be recycled into a new [`BFSTraversal`] or [`super::BFSFullTraversal`]
     pub fn extract_resources(self) -> BFSResources {
         BFSResources {
             parents: Some(self.parents),
             queue: Some(self.queue),
+            sources: None,
         }
     }
 
@@ -63,6 +72,9 @@ impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> Iterator
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(current_node) = self.queue.pop_front() {
+            if current_node.depth >= self.max_depth {
+                return Some(current_node);
+            }
             let neighbors = self.graph.neighbor_iterator(current_node.vertex).unwrap();
             for neighbor in neighbors {
                 if self.parents[neighbor] == usize::MAX {
@@ -0,0 +1,267 @@
+use std::{collections::VecDeque, error::Error, fmt::Display};
+
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+use crate::lex_bfs::maximum_cardinality_search;
+
+/// A chordless cycle of length at least 4, which shows that a graph is not chordal.
+///
+/// `cycle` lists the vertices of the cycle in order, with the edge from the last vertex back to the first closing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChordlessCycle {
+    pub cycle: Vec<usize>,
+}
+
+impl Display for ChordlessCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "graph is not chordal, as it contains the chordless cycle {:?}",
+            self.cycle
+        )
+    }
+}
+
+impl Error for ChordlessCycle {}
+
+/// A visiting order along with, for each vertex, its neighbors visited before it.
+/// For a chordal graph the reverse of the order is a perfect elimination ordering,
+/// and the earlier neighbors of each vertex form a clique.
+struct Elimination {
+    order: Vec<usize>,
+    number: Vec<usize>,
+    earlier: Vec<Vec<usize>>,
+}
+
+impl Elimination {
+    fn new<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(graph: &G) -> Self {
+        let n = graph.num_v_labels();
+        let order = maximum_cardinality_search(graph);
+        let mut number = vec![usize::MAX; n];
+        for (i, &v) in order.iter().enumerate() {
+            number[v] = i;
+        }
+        let mut earlier = vec![vec![]; n];
+        let mut seen = vec![usize::MAX; n];
+        for &v in &order {
+            for w in graph.neighbor_iterator(v).unwrap() {
+                if number[w] < number[v] && seen[w] != v {
+                    seen[w] = v;
+                    earlier[v].push(w);
+                }
+            }
+        }
+        Self {
+            order,
+            number,
+            earlier,
+        }
+    }
+
+    /// The latest visited of the earlier neighbors of `v`.
+    fn follower(&self, v: usize) -> Option<usize> {
+        self.earlier[v]
+            .iter()
+            .copied()
+            .max_by_key(|&u| self.number[u])
+    }
+
+    /// Checks that the earlier neighbors of each vertex form a clique, as in Tarjan and Yannakakis.
+    /// It suffices that they are all earlier neighbors of its follower.
+    /// On failure, returns a vertex along with two of its earlier neighbors which are not adjacent.
+    fn verify(&self) -> Result<(), (usize, usize, usize)> {
+        let n = self.number.len();
+        let mut required = vec![vec![]; n];
+        for &v in &self.order {
+            if let Some(p) = self.follower(v) {
+                for &u in &self.earlier[v] {
+                    if u != p {
+                        required[p].push((u, v));
+                    }
+                }
+            }
+        }
+        let mut marked = vec![usize::MAX; n];
+        for &p in &self.order {
+            for &u in &self.earlier[p] {
+                marked[u] = p;
+            }
+            for &(u, v) in &required[p] {
+                if marked[u] != p {
+                    return Err((v, u, p));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Finds a chordless cycle through `v`, `a` and `b`, where `a` and `b` are non-adjacent neighbors of `v`,
+/// as a shortest path from `a` to `b` avoiding the other neighbors of `v`.
+fn chordless_cycle_through<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+    v: usize,
+    a: usize,
+    b: usize,
+) -> Option<Vec<usize>> {
+    let n = graph.num_v_labels();
+    let mut parent = vec![usize::MAX; n];
+    parent[v] = v;
+    for w in graph.neighbor_iterator(v).unwrap() {
+        parent[w] = w;
+    }
+    parent[a] = a;
+    parent[b] = usize::MAX;
+
+    let mut queue = VecDeque::from([a]);
+    while let Some(u) = queue.pop_front() {
+        for w in graph.neighbor_iterator(u).unwrap() {
+            if parent[w] != usize::MAX {
+                continue;
+            }
+            parent[w] = u;
+            if w == b {
+                let mut cycle = vec![v, b];
+                let mut x = b;
+                while x != a {
+                    x = parent[x];
+                    cycle.push(x);
+                }
+                return Some(cycle);
+            }
+            queue.push_back(w);
+        }
+    }
+    None
+}
+
+/// Finds a chordless cycle given a vertex with two non-adjacent earlier neighbors.
+///
+/// The cycle through that vertex is tried first. Every chordless cycle passes through some vertex between two
+/// non-adjacent neighbors, so trying every such triple always succeeds for a graph which is not chordal.
+fn find_chordless_cycle<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+    (v, a, b): (usize, usize, usize),
+) -> ChordlessCycle {
+    if let Some(cycle) = chordless_cycle_through(graph, v, a, b) {
+        return ChordlessCycle { cycle };
+    }
+    let n = graph.num_v_labels();
+    let mut adjacent = vec![usize::MAX; n];
+    for v in graph.distinct_vertices() {
+        let mut neighbors = graph.neighbor_iterator(v).unwrap().collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        for (i, &a) in neighbors.iter().enumerate() {
+            for w in graph.neighbor_iterator(a).unwrap() {
+                adjacent[w] = a;
+            }
+            for &b in &neighbors[i + 1..] {
+                if adjacent[b] == a {
+                    continue;
+                }
+                if let Some(cycle) = chordless_cycle_through(graph, v, a, b) {
+                    return ChordlessCycle { cycle };
+                }
+            }
+        }
+    }
+    unreachable!("a graph without a perfect elimination ordering contains a chordless cycle")
+}
+
+/// Computes a perfect elimination ordering from a maximum cardinality search, or a chordless cycle.
+pub(crate) fn perfect_elimination_ordering<
+    G: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+>(
+    graph: &G,
+) -> Result<Vec<usize>, ChordlessCycle> {
+    let elimination = Elimination::new(graph);
+    match elimination.verify() {
+        Ok(()) => Ok(elimination.order.into_iter().rev().collect()),
+        Err(witness) => Err(find_chordless_cycle(graph, witness)),
+    }
+}
+
+/// A clique tree (or forest) of a chordal graph.
+///
+/// Its nodes are the maximal cliques of the graph, and for each vertex the cliques containing it form a subtree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CliqueTree {
+    pub cliques: Vec<Vec<usize>>,
+    /// The parent of each clique, or `usize::MAX` for the root of each tree.
+    pub parents: Vec<usize>,
+    pub(crate) adjacency: Vec<Vec<usize>>,
+}
+
+impl CliqueTree {
+    /// Builds the clique tree by adding vertices in their visiting order.
+    /// Each vertex either extends the clique which is exactly its earlier neighbors, or starts a new clique
+    /// attached to a clique containing them.
+    pub(crate) fn build<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+        graph: &G,
+    ) -> Result<Self, ChordlessCycle> {
+        let elimination = Elimination::new(graph);
+        if let Err(witness) = elimination.verify() {
+            return Err(find_chordless_cycle(graph, witness));
+        }
+
+        let mut cliques: Vec<Vec<usize>> = vec![];
+        let mut parents = vec![];
+        let mut clique_of = vec![usize::MAX; elimination.number.len()];
+        for &v in &elimination.order {
+            let earlier = &elimination.earlier[v];
+            let clique = match elimination.follower(v) {
+                Some(p) if cliques[clique_of[p]].len() == earlier.len() => clique_of[p],
+                follower => {
+                    let mut clique = earlier.clone();
+                    clique.sort_by_key(|&u| elimination.number[u]);
+                    cliques.push(clique);
+                    parents.push(follower.map_or(usize::MAX, |p| clique_of[p]));
+                    cliques.len() - 1
+                }
+            };
+            cliques[clique].push(v);
+            clique_of[v] = clique;
+        }
+
+        let mut adjacency = vec![vec![]; cliques.len()];
+        for (c, &p) in parents.iter().enumerate() {
+            if p != usize::MAX {
+                adjacency[c].push(p);
+                adjacency[p].push(c);
+            }
+        }
+        Ok(Self {
+            cliques,
+            parents,
+            adjacency,
+        })
+    }
+}
+
+impl ViewCombinator for CliqueTree {
+    type VertexLabel = usize;
+
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        0..self.adjacency.len()
+    }
+
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(u, neigh)| neigh.iter().map(move |&v| (u, v)))
+    }
+
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> Option<impl Iterator<Item = usize> + 'a> {
+        self.adjacency
+            .get(vertex)
+            .map(|neigh| neigh.iter().copied())
+    }
+}
+
+impl ExactCombinator for CliqueTree {
+    fn num_v_labels(&self) -> usize {
+        self.adjacency.len()
+    }
+}
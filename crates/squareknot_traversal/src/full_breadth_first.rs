@@ -26,6 +26,7 @@ impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> BFSFullTraver
         BFSResources {
             parents: Some(self.parents),
             queue: Some(self.queue),
+            sources: None,
         }
     }
     /// Iterate over the vertices in the graph which have been traversed
@@ -60,7 +61,7 @@ impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> Iterator
                 }
                 return Some(current_node);
             } else {
-                while let Some(v) = self.vertex_order.next() {
+                for v in self.vertex_order.by_ref() {
                     if self.parents[v] != usize::MAX {
                         continue;
                     }
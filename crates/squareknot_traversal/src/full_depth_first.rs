@@ -34,6 +34,14 @@ impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator, const POST_OR
         }
     }
 
+    /// Creates a full traversal which starts new trees at unvisited vertices in the order given by `vertex_order`.
+    pub fn with_vertex_order<I: Iterator<Item = usize> + 'a>(graph: &'a G, vertex_order: I) -> Self {
+        Self {
+            vertex_order: Box::new(vertex_order),
+            ..Self::new(graph)
+        }
+    }
+
     /// Extracts [`DFSResources`] which may be recycled into a new [`DFSFullTraversal`] or [`DFSFullTraversal`]
     pub fn extract_resources(self) -> DFSResources {
         DFSResources {
@@ -80,7 +88,7 @@ impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> Iterator
                 return Some(current_node);
             } else {
                 // Find next unvisited vertex for full traversal
-                while let Some(v) = self.vertex_order.next() {
+                for v in self.vertex_order.by_ref() {
                     if self.parents[v] != usize::MAX {
                         continue;
                     }
@@ -139,7 +147,7 @@ impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> Iterator
                 }
             } else {
                 // Find next unvisited vertex for full traversal
-                while let Some(v) = self.vertex_order.next() {
+                for v in self.vertex_order.by_ref() {
                     if self.parents[v] != usize::MAX {
                         continue;
                     }
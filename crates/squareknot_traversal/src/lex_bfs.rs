@@ -0,0 +1,116 @@
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+/// A class of the partition maintained by [`lex_bfs`], occupying `start..end` of the sequence.
+struct Class {
+    start: usize,
+    end: usize,
+    /// The class split off from this one in the current round, if any.
+    split: usize,
+    split_round: usize,
+}
+
+/// Lexicographic breadth first search by partition refinement, in time `O(n + m)`.
+///
+/// The unvisited vertices are kept in a sequence of classes. Visiting a vertex moves each of its unvisited neighbors
+/// into a new class placed directly before its old one, so the next vertex is always at the front of the sequence.
+pub(crate) fn lex_bfs<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+) -> Vec<usize> {
+    let n = graph.num_v_labels();
+    let mut sequence = graph.distinct_vertices();
+    let mut position = vec![usize::MAX; n];
+    for (i, &v) in sequence.iter().enumerate() {
+        position[v] = i;
+    }
+    let mut classes = vec![Class {
+        start: 0,
+        end: sequence.len(),
+        split: usize::MAX,
+        split_round: usize::MAX,
+    }];
+    let mut class_of = vec![0; n];
+    // The last round in which each vertex was moved, so repeated neighbors are moved once
+    let mut moved = vec![usize::MAX; n];
+
+    for i in 0..sequence.len() {
+        let v = sequence[i];
+        classes[class_of[v]].start += 1;
+
+        for w in graph.neighbor_iterator(v).unwrap() {
+            if position[w] <= i || moved[w] == i {
+                continue;
+            }
+            moved[w] = i;
+            let c = class_of[w];
+            if classes[c].split_round != i {
+                let start = classes[c].start;
+                classes.push(Class {
+                    start,
+                    end: start,
+                    split: usize::MAX,
+                    split_round: usize::MAX,
+                });
+                classes[c].split = classes.len() - 1;
+                classes[c].split_round = i;
+            }
+            let d = classes[c].split;
+
+            // Swap `w` to the front of its class, then move the boundary past it
+            let front = classes[c].start;
+            let u = sequence[front];
+            sequence.swap(front, position[w]);
+            position[u] = position[w];
+            position[w] = front;
+            classes[c].start += 1;
+            classes[d].end += 1;
+            class_of[w] = d;
+        }
+    }
+    sequence
+}
+
+/// Maximum cardinality search, which repeatedly visits an unvisited vertex with the most visited neighbors.
+///
+/// Vertices are kept in buckets by their number of visited neighbors. Moving a vertex leaves a stale entry behind,
+/// which is skipped when reached, so the search runs in time `O(n + m)`.
+pub(crate) fn maximum_cardinality_search<
+    G: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+>(
+    graph: &G,
+) -> Vec<usize> {
+    let n = graph.num_v_labels();
+    let vertices = graph.distinct_vertices();
+    let mut weight = vec![0; n];
+    let mut visited = vec![false; n];
+    let mut counted = vec![usize::MAX; n];
+    let mut buckets = vec![vertices.iter().rev().copied().collect::<Vec<_>>()];
+    let mut max = 0;
+    let mut order = Vec::with_capacity(vertices.len());
+
+    while order.len() < vertices.len() {
+        let Some(v) = buckets[max].pop() else {
+            max -= 1;
+            continue;
+        };
+        if visited[v] || weight[v] != max {
+            continue;
+        }
+        visited[v] = true;
+        let round = order.len();
+        order.push(v);
+
+        for w in graph.neighbor_iterator(v).unwrap() {
+            if visited[w] || counted[w] == round {
+                continue;
+            }
+            counted[w] = round;
+            weight[w] += 1;
+            if weight[w] == buckets.len() {
+                buckets.push(vec![]);
+            }
+            buckets[weight[w]].push(w);
+            max = max.max(weight[w]);
+        }
+    }
+    order
+}
@@ -11,8 +11,30 @@ pub use depth_first::*;
 pub mod full_depth_first;
 pub use full_depth_first::*;
 
+pub mod multi_source;
+pub use multi_source::*;
+
 pub mod recycle;
 
+pub mod biconnected;
+pub use biconnected::*;
+
+pub mod chordal;
+pub use chordal::*;
+
+pub mod lex_bfs;
+
+pub mod strongly_connected;
+pub use strongly_connected::*;
+
+pub mod visitor;
+pub use visitor::*;
+
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::ParallelBFS;
+
 use squareknot_graph::{ExactCombinator, ViewCombinator};
 
 #[derive(Clone, Copy, Hash, Debug)]
@@ -25,36 +47,172 @@ pub struct TraversalNode {
 /// Trait implementing traversal methods on graphs with `usize` vertices
 pub trait TraversalView: ViewCombinator<VertexLabel = usize> + ExactCombinator {
     /// Traverses the graph starting from `root`.
-    fn bfs(&self, root: usize) -> BFSTraversal<Self> {
+    fn bfs(&self, root: usize) -> BFSTraversal<'_, Self> {
         BFSTraversal::new(self, root)
     }
 
+    /// Traverses the vertices within distance `max_depth` of `root`.
+    fn depth_limited_bfs(&self, root: usize, max_depth: usize) -> BFSTraversal<'_, Self> {
+        BFSTraversal::new(self, root).limit_depth(max_depth)
+    }
+
+    /// Traverses the graph starting from every vertex of `roots` at once, recording the nearest root of each vertex.
+    fn multi_source_bfs<I: IntoIterator<Item = usize>>(
+        &self,
+        roots: I,
+    ) -> MultiSourceBFS<'_, Self> {
+        MultiSourceBFS::new(self, roots)
+    }
+
+    /// Runs a level-synchronous parallel breadth first search from `root`, switching between top-down and bottom-up
+    /// expansion of each level. The graph is treated as undirected, see [`TraversalView::parallel_bfs_with`] otherwise.
+    #[cfg(feature = "parallel")]
+    fn parallel_bfs(&self, root: usize) -> ParallelBFS
+    where
+        Self: Sync,
+    {
+        parallel::direction_optimizing_bfs(self, self, root)
+    }
+
+    /// Runs a parallel breadth first search from `root` along out-neighbors,
+    /// where `reverse` is the transpose of the graph and supplies in-neighbors for bottom-up expansion.
+    #[cfg(feature = "parallel")]
+    fn parallel_bfs_with<R: ViewCombinator<VertexLabel = usize> + Sync>(
+        &self,
+        reverse: &R,
+        root: usize,
+    ) -> ParallelBFS
+    where
+        Self: Sync,
+    {
+        parallel::direction_optimizing_bfs(self, reverse, root)
+    }
+
     /// Traverses the graph starting from `root`.
-    fn dfs(&self, root: usize) -> DFSTraversal<Self, false> {
+    fn dfs(&self, root: usize) -> DFSTraversal<'_, Self, false> {
         DFSTraversal::new(self, root)
     }
 
     /// Traverses the graph starting from `root`.
-    fn dfs_post_order(&self, root: usize) -> DFSTraversal<Self, true> {
+    fn dfs_post_order(&self, root: usize) -> DFSTraversal<'_, Self, true> {
         DFSTraversal::new(self, root)
     }
 
     /// Creates a full traversal of the graph starting from first vertex from the graph (See [`AbstractGraph::vertex_iterator`]).
-    fn full_bfs(&self) -> BFSFullTraversal<Self> {
+    fn full_bfs(&self) -> BFSFullTraversal<'_, Self> {
         BFSFullTraversal::new(self)
     }
 
-    fn full_dfs(&self) -> DFSFullTraversal<Self, false> {
+    fn full_dfs(&self) -> DFSFullTraversal<'_, Self, false> {
         DFSFullTraversal::new(self)
     }
 
-    fn full_dfs_post_order(&self) -> DFSFullTraversal<Self, true> {
+    fn full_dfs_post_order(&self) -> DFSFullTraversal<'_, Self, true> {
         DFSFullTraversal::new(self)
     }
 
+    /// Runs a depth first search from `root`, reporting events to `visitor`.
+    /// Returns [`Control::Break`] if the visitor stopped the search, and [`Control::Continue`] otherwise.
+    fn dfs_visit<V: DfsVisitor + ?Sized>(&self, root: usize, visitor: &mut V) -> Control {
+        visitor::depth_first_visit(self, [root], visitor)
+    }
+
+    /// Runs a depth first search from every vertex in turn (See [`TraversalView::full_dfs`]), reporting events to `visitor`.
+    fn full_dfs_visit<V: DfsVisitor + ?Sized>(&self, visitor: &mut V) -> Control {
+        visitor::depth_first_visit(self, self.vertex_iterator(), visitor)
+    }
+
     fn component_count(&self) -> usize {
         self.full_dfs().filter(|n| n.depth == 0).count()
     }
+
+    /// Computes articulation points, bridges, biconnected components and 2-edge-connected components in one search.
+    /// The graph is treated as undirected.
+    fn biconnectivity(&self) -> Biconnectivity {
+        biconnected::lowpoint_search(self)
+    }
+
+    fn articulation_points(&self) -> Vec<usize> {
+        self.biconnectivity().articulation_points
+    }
+
+    fn bridges(&self) -> Vec<(usize, usize)> {
+        self.biconnectivity().bridges
+    }
+
+    /// Partitions the edges into biconnected components.
+    fn biconnected_components(&self) -> Vec<Vec<(usize, usize)>> {
+        self.biconnectivity().blocks
+    }
+
+    /// Partitions the vertices into maximal sets which remain connected after removing any one edge.
+    fn two_edge_connected_components(&self) -> Vec<Vec<usize>> {
+        self.biconnectivity().two_edge_connected_components
+    }
+
+    fn block_cut_tree(&self) -> BlockCutTree {
+        BlockCutTree::build(self, self.biconnectivity())
+    }
+
+    /// Orders the vertices by lexicographic breadth first search.
+    /// For a chordal graph, the reverse of this order is a perfect elimination ordering.
+    fn lex_bfs(&self) -> Vec<usize> {
+        lex_bfs::lex_bfs(self)
+    }
+
+    /// Orders the vertices by maximum cardinality search.
+    /// For a chordal graph, the reverse of this order is a perfect elimination ordering.
+    fn maximum_cardinality_search(&self) -> Vec<usize> {
+        lex_bfs::maximum_cardinality_search(self)
+    }
+
+    /// Returns an order in which every vertex forms a clique with its neighbors later in the order,
+    /// or a chordless cycle if there is no such order.
+    fn perfect_elimination_ordering(&self) -> Result<Vec<usize>, ChordlessCycle> {
+        chordal::perfect_elimination_ordering(self)
+    }
+
+    fn is_chordal(&self) -> bool {
+        self.perfect_elimination_ordering().is_ok()
+    }
+
+    /// Lists the maximal cliques of a chordal graph, of which there are at most `n`.
+    fn chordal_maximal_cliques(&self) -> Result<Vec<Vec<usize>>, ChordlessCycle> {
+        CliqueTree::build(self).map(|tree| tree.cliques)
+    }
+
+    /// Computes the chromatic number of a chordal graph, which is the size of its largest clique.
+    fn chordal_chromatic_number(&self) -> Result<usize, ChordlessCycle> {
+        let cliques = self.chordal_maximal_cliques()?;
+        Ok(cliques.iter().map(|c| c.len()).max().unwrap_or(0))
+    }
+
+    fn clique_tree(&self) -> Result<CliqueTree, ChordlessCycle> {
+        CliqueTree::build(self)
+    }
+
+    /// Finds the strongly connected components with Tarjan's algorithm.
+    /// Neighbors are treated as out-neighbors, so for undirected graphs these are the connected components.
+    fn strongly_connected_components(&self) -> StronglyConnectedComponents {
+        strongly_connected::tarjan(self)
+    }
+
+    /// Finds the strongly connected components with Kosaraju's algorithm.
+    /// The result is the same as [`TraversalView::strongly_connected_components`], up to the order of each component.
+    fn kosaraju_components(&self) -> StronglyConnectedComponents {
+        strongly_connected::kosaraju(self)
+    }
+
+    /// Creates the condensation of the graph, which is acyclic.
+    fn condensation(&self) -> Condensation {
+        Condensation::build(self, self.strongly_connected_components())
+    }
+
+    /// Orders the vertices so that for every edge `(u, v)`, `u` comes before `v`.
+    /// Returns a cycle instead if there is no such order.
+    fn topological_sort(&self) -> Result<Vec<usize>, DirectedCycle> {
+        strongly_connected::topological_sort(self)
+    }
 }
 
 impl<G: ViewCombinator<VertexLabel = usize> + ExactCombinator> TraversalView for G {}
@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+use crate::{recycle::BFSResources, TraversalNode};
+
+/// A breadth first traversal started from several roots at once, all at depth 0.
+///
+/// Each vertex records the root it was reached from, which is a nearest root.
+/// Ties are broken in favor of roots given earlier.
+pub struct MultiSourceBFS<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> {
+    pub(crate) graph: &'a G,
+    pub(crate) parents: Vec<usize>,
+    pub(crate) sources: Vec<usize>,
+    pub(crate) queue: VecDeque<TraversalNode>,
+    pub(crate) max_depth: usize,
+}
+
+impl<'a, G: ViewCombinator<VertexLabel = usize> + ExactCombinator> MultiSourceBFS<'a, G> {
+    pub fn new<I: IntoIterator<Item = usize>>(graph: &'a G, roots: I) -> Self {
+        let mut traversal = Self {
+            graph,
+            parents: vec![usize::MAX; graph.num_v_labels()],
+            sources: vec![usize::MAX; graph.num_v_labels()],
+            queue: VecDeque::new(),
+            max_depth: usize::MAX,
+        };
+        traversal.seed(roots);
+        traversal
+    }
+
+    /// Enqueues each root which has not been reached yet.
+    pub(crate) fn seed<I: IntoIterator<Item = usize>>(&mut self, roots: I) {
+        for root in roots {
+            if self.parents[root] != usize::MAX {
+                continue;
+            }
+            self.parents[root] = root;
+            self.sources[root] = root;
+            self.queue.push_back(TraversalNode {
+                vertex: root,
+                depth: 0,
+                parent: root,
+            });
+        }
+    }
+
+    /// Stops the traversal from enqueueing vertices deeper than `max_depth`.
+    pub fn limit_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Extracts [`BFSResources`] which may be recycled into a new traversal.
+    pub fn extract_resources(self) -> BFSResources {
+        BFSResources {
+            parents: Some(self.parents),
+            queue: Some(self.queue),
+            sources: Some(self.sources),
+        }
+    }
+
+    pub fn restart_at<I: IntoIterator<Item = usize>>(&mut self, roots: I) {
+        self.parents.fill(usize::MAX);
+        self.sources.fill(usize::MAX);
+        self.queue.clear();
+        self.seed(roots);
+    }
+
+    /// Returns the root from which `vertex` was reached, if it has been traversed.
+    pub fn source_of(&self, vertex: usize) -> Option<usize> {
+        self.sources
+            .get(vertex)
+            .copied()
+            .filter(|&s| s != usize::MAX)
+    }
+
+    /// Returns the root from which each vertex was reached, indexed by label.
+    /// Vertices which have not been traversed map to `usize::MAX`.
+    pub fn sources(&self) -> &[usize] {
+        &self.sources
+    }
+
+    /// Iterate over the vertices in the graph which have been traversed
+    pub fn traversed_iter<'b>(&'b self) -> impl Iterator<Item = usize> + 'b {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|(_, &x)| x != usize::MAX)
+            .map(|(i, _)| i)
+    }
+}
+
+impl<G: ViewCombinator<VertexLabel = usize> + ExactCombinator> Iterator for MultiSourceBFS<'_, G> {
+    type Item = TraversalNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current_node = self.queue.pop_front()?;
+        if current_node.depth < self.max_depth {
+            let source = self.sources[current_node.vertex];
+            for neighbor in self.graph.neighbor_iterator(current_node.vertex).unwrap() {
+                if self.parents[neighbor] == usize::MAX {
+                    self.parents[neighbor] = current_node.vertex;
+                    self.sources[neighbor] = source;
+                    self.queue.push_back(TraversalNode {
+                        vertex: neighbor,
+                        depth: current_node.depth + 1,
+                        parent: current_node.vertex,
+                    });
+                }
+            }
+        }
+        Some(current_node)
+    }
+}
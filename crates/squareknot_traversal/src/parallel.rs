@@ -0,0 +1,114 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+/// Switch to bottom-up once the frontier has more than `1 / ALPHA` of the unexplored edges.
+const ALPHA: usize = 14;
+/// Switch back to top-down once the frontier has fewer than `1 / BETA` of the vertices.
+const BETA: usize = 24;
+
+/// The breadth first search tree found by [`crate::TraversalView::parallel_bfs`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParallelBFS {
+    /// The distance from the root to each label, or `usize::MAX` for labels which were not reached.
+    pub depths: Vec<usize>,
+    /// The parent of each label in the search tree, or `usize::MAX` for labels which were not reached.
+    /// The root is its own parent.
+    pub parents: Vec<usize>,
+}
+
+impl ParallelBFS {
+    /// Iterate over the vertices in the graph which have been traversed
+    pub fn traversed_iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|(_, &x)| x != usize::MAX)
+            .map(|(i, _)| i)
+    }
+}
+
+/// Beamer's direction-optimizing breadth first search.
+///
+/// Each level is expanded in parallel, either top-down by scanning the out-neighbors of the frontier,
+/// or bottom-up by scanning the in-neighbors (the neighbors in `reverse`) of every unvisited vertex.
+pub(crate) fn direction_optimizing_bfs<G, R>(graph: &G, reverse: &R, root: usize) -> ParallelBFS
+where
+    G: ViewCombinator<VertexLabel = usize> + ExactCombinator + Sync,
+    R: ViewCombinator<VertexLabel = usize> + Sync,
+{
+    let n = graph.num_v_labels();
+    let parents: Vec<AtomicUsize> = (0..n).map(|_| AtomicUsize::new(usize::MAX)).collect();
+    let depths: Vec<AtomicUsize> = (0..n).map(|_| AtomicUsize::new(usize::MAX)).collect();
+    let degrees: Vec<usize> = (0..n)
+        .into_par_iter()
+        .map(|v| graph.neighbor_iterator(v).map_or(0, |it| it.count()))
+        .collect();
+    let num_vertices = (0..n)
+        .into_par_iter()
+        .filter(|&v| graph.neighbor_iterator(v).is_some())
+        .count();
+
+    parents[root].store(root, Ordering::Relaxed);
+    depths[root].store(0, Ordering::Relaxed);
+    let mut frontier = vec![root];
+    let mut unexplored_edges = degrees.par_iter().sum::<usize>() - degrees[root];
+    let mut bottom_up = false;
+    let mut level = 0;
+
+    while !frontier.is_empty() {
+        let frontier_edges: usize = frontier.par_iter().map(|&v| degrees[v]).sum();
+        if !bottom_up && frontier_edges > unexplored_edges / ALPHA {
+            bottom_up = true;
+        } else if bottom_up && frontier.len() < num_vertices / BETA {
+            bottom_up = false;
+        }
+
+        frontier = if bottom_up {
+            (0..n)
+                .into_par_iter()
+                .filter(|&v| {
+                    if parents[v].load(Ordering::Relaxed) != usize::MAX {
+                        return false;
+                    }
+                    let Some(mut in_neighbors) = reverse.neighbor_iterator(v) else {
+                        return false;
+                    };
+                    match in_neighbors.find(|&u| depths[u].load(Ordering::Relaxed) == level) {
+                        Some(u) => {
+                            parents[v].store(u, Ordering::Relaxed);
+                            depths[v].store(level + 1, Ordering::Relaxed);
+                            true
+                        }
+                        None => false,
+                    }
+                })
+                .collect()
+        } else {
+            frontier
+                .par_iter()
+                .flat_map_iter(|&u| {
+                    let (parents, depths) = (&parents, &depths);
+                    graph.neighbor_iterator(u).unwrap().filter(move |&w| {
+                        let claimed = parents[w]
+                            .compare_exchange(usize::MAX, u, Ordering::Relaxed, Ordering::Relaxed)
+                            .is_ok();
+                        if claimed {
+                            depths[w].store(level + 1, Ordering::Relaxed);
+                        }
+                        claimed
+                    })
+                })
+                .collect()
+        };
+
+        unexplored_edges -= frontier.par_iter().map(|&v| degrees[v]).sum::<usize>();
+        level += 1;
+    }
+
+    ParallelBFS {
+        depths: depths.into_iter().map(AtomicUsize::into_inner).collect(),
+        parents: parents.into_iter().map(AtomicUsize::into_inner).collect(),
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::VecDeque;
 
-use crate::{BFSFullTraversal, BFSTraversal, DFSFullTraversal, DFSTraversal, TraversalNode, TraversalView};
+use crate::{
+    BFSFullTraversal, BFSTraversal, DFSFullTraversal, DFSTraversal, MultiSourceBFS, TraversalNode,
+    TraversalView,
+};
 
 /*use crate::{
     BFSFullTraversal, BFSTraversal, DFSFullTraversal, DFSTraversal, TraversalView as TraversalView,
@@ -10,14 +13,31 @@ use crate::{BFSFullTraversal, BFSTraversal, DFSFullTraversal, DFSTraversal, Trav
 pub struct BFSResources {
     pub(crate) parents: Option<Vec<usize>>,
     pub(crate) queue: Option<VecDeque<TraversalNode>>,
+    pub(crate) sources: Option<Vec<usize>>,
+}
+
+impl Default for BFSResources {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BFSResources {
+    /// Creates empty resources, which grow to fit the first graph they are used on.
+    pub fn new() -> Self {
+        Self {
+            parents: Some(Vec::new()),
+            queue: Some(VecDeque::new()),
+            sources: Some(Vec::new()),
+        }
+    }
+
     fn reset(mut self, new_size: usize, new_root: usize) -> (Vec<usize>, VecDeque<TraversalNode>) {
         let mut v = self.parents.take().unwrap();
         let mut q = self.queue.take().unwrap();
         v.fill(usize::MAX);
         v.resize(new_size, usize::MAX);
+        v[new_root] = new_root;
 
         q.clear();
         q.push_back(TraversalNode {
@@ -28,17 +48,45 @@ impl BFSResources {
         (v, q)
     }
 
-    pub fn bfs<G: TraversalView>(self, graph: &G, root: usize) -> BFSTraversal<G> {
+    pub fn bfs<G: TraversalView>(self, graph: &G, root: usize) -> BFSTraversal<'_, G> {
         let (v, q) = self.reset(graph.num_v_labels(), root);
 
         BFSTraversal {
             graph,
             parents: v,
             queue: q,
+            max_depth: usize::MAX,
         }
     }
 
-    pub fn full_bfs<G: TraversalView>(self, graph: &G, root: usize) -> BFSFullTraversal<G> {
+    pub fn multi_source_bfs<G: TraversalView, I: IntoIterator<Item = usize>>(
+        mut self,
+        graph: &G,
+        roots: I,
+    ) -> MultiSourceBFS<'_, G> {
+        let n = graph.num_v_labels();
+        let mut parents = self.parents.take().unwrap();
+        let mut queue = self.queue.take().unwrap();
+        // Resources extracted from single source traversals carry no source buffer
+        let mut sources = self.sources.take().unwrap_or_default();
+        parents.fill(usize::MAX);
+        parents.resize(n, usize::MAX);
+        sources.fill(usize::MAX);
+        sources.resize(n, usize::MAX);
+        queue.clear();
+
+        let mut traversal = MultiSourceBFS {
+            graph,
+            parents,
+            sources,
+            queue,
+            max_depth: usize::MAX,
+        };
+        traversal.seed(roots);
+        traversal
+    }
+
+    pub fn full_bfs<G: TraversalView>(self, graph: &G, root: usize) -> BFSFullTraversal<'_, G> {
         let (v, q) = self.reset(graph.num_v_labels(), root);
 
         BFSFullTraversal {
@@ -56,7 +104,22 @@ pub struct DFSResources {
     pub(crate) output: Option<Vec<bool>>
 }
 
+impl Default for DFSResources {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DFSResources {
+    /// Creates empty resources, which grow to fit the first graph they are used on.
+    pub fn new() -> Self {
+        Self {
+            parents: Some(Vec::new()),
+            stack: Some(Vec::new()),
+            output: Some(Vec::new()),
+        }
+    }
+
     fn reset(mut self, new_size: usize, new_root: usize) -> (Vec<usize>, Vec<TraversalNode>, Vec<bool>) {
         let mut v = self.parents.take().unwrap();
         let mut q = self.stack.take().unwrap();
@@ -75,7 +138,7 @@ impl DFSResources {
         (v, q, o)
     }
 
-    pub fn dfs<G: TraversalView>(self, graph: &G, root: usize) -> DFSTraversal<G, false> {
+    pub fn dfs<G: TraversalView>(self, graph: &G, root: usize) -> DFSTraversal<'_, G, false> {
         let (v, q, o) = self.reset(graph.num_v_labels(), root);
 
         DFSTraversal {
@@ -86,7 +149,11 @@ impl DFSResources {
         }
     }
 
-    pub fn dfs_post_order<G: TraversalView>(self, graph: &G, root: usize) -> DFSTraversal<G, true> {
+    pub fn dfs_post_order<G: TraversalView>(
+        self,
+        graph: &G,
+        root: usize,
+    ) -> DFSTraversal<'_, G, true> {
         let (v, q, mut o) = self.reset(graph.num_v_labels(), root);
 
         o.resize(graph.num_v_labels(), false);
@@ -99,7 +166,11 @@ impl DFSResources {
         }
     }
 
-    pub fn full_dfs<G: TraversalView>(self, graph: &G, root: usize) -> DFSFullTraversal<G, false> {
+    pub fn full_dfs<G: TraversalView>(
+        self,
+        graph: &G,
+        root: usize,
+    ) -> DFSFullTraversal<'_, G, false> {
         let (v, q, o) = self.reset(graph.num_v_labels(), root);
 
         DFSFullTraversal {
@@ -111,7 +182,7 @@ impl DFSResources {
         }
     }
 
-    pub fn full_dfs_post_order<G: TraversalView>(self, graph: &G, root: usize) -> DFSFullTraversal<G, true> {
+    pub fn full_dfs_post_order<G: TraversalView>(self, graph: &G, root: usize) -> DFSFullTraversal<'_, G, true> {
         let (v, q, mut o) = self.reset(graph.num_v_labels(), root);
 
         o.resize(graph.num_v_labels(), false);
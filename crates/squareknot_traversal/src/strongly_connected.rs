@@ -0,0 +1,313 @@
+use std::{error::Error, fmt::Display};
+
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+use crate::{visitor::depth_first_visit, Control, DFSFullTraversal, DfsVisitor};
+
+/// The strongly connected components of a graph, treating [`ViewCombinator::neighbor_iterator`] as out-neighbors.
+///
+/// Components are listed in topological order of the condensation, so every edge between two components
+/// leads from a lower index to a higher one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StronglyConnectedComponents {
+    pub components: Vec<Vec<usize>>,
+    /// The index of the component containing each label, or `usize::MAX` for labels not in the graph.
+    pub component_of: Vec<usize>,
+}
+
+impl StronglyConnectedComponents {
+    pub fn count(&self) -> usize {
+        self.components.len()
+    }
+
+    fn from_components(components: Vec<Vec<usize>>, num_v_labels: usize) -> Self {
+        let mut component_of = vec![usize::MAX; num_v_labels];
+        for (i, component) in components.iter().enumerate() {
+            for &v in component {
+                component_of[v] = i;
+            }
+        }
+        Self {
+            components,
+            component_of,
+        }
+    }
+}
+
+/// The state of Tarjan's algorithm, which is driven by the events of a depth first search.
+struct Tarjan {
+    /// The discovery time of each vertex.
+    index: Vec<usize>,
+    lowlink: Vec<usize>,
+    on_stack: Vec<bool>,
+    /// Vertices whose component has not been completed, in order of discovery.
+    stack: Vec<usize>,
+    /// The active path of the search, so that a finished vertex can update its parent.
+    path: Vec<usize>,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan {
+    fn update(&mut self, u: usize, v: usize) -> Control {
+        if self.on_stack[v] {
+            self.lowlink[u] = self.lowlink[u].min(self.index[v]);
+        }
+        Control::Continue
+    }
+}
+
+impl DfsVisitor for Tarjan {
+    fn discover_vertex(&mut self, vertex: usize, time: usize) -> Control {
+        self.index[vertex] = time;
+        self.lowlink[vertex] = time;
+        self.on_stack[vertex] = true;
+        self.stack.push(vertex);
+        self.path.push(vertex);
+        Control::Continue
+    }
+
+    fn back_edge(&mut self, u: usize, v: usize) -> Control {
+        self.update(u, v)
+    }
+
+    fn forward_or_cross_edge(&mut self, u: usize, v: usize) -> Control {
+        self.update(u, v)
+    }
+
+    fn finish_vertex(&mut self, vertex: usize, _time: usize) -> Control {
+        self.path.pop();
+        if let Some(&parent) = self.path.last() {
+            self.lowlink[parent] = self.lowlink[parent].min(self.lowlink[vertex]);
+        }
+        if self.lowlink[vertex] == self.index[vertex] {
+            let mut component = vec![];
+            loop {
+                let w = self.stack.pop().unwrap();
+                self.on_stack[w] = false;
+                component.push(w);
+                if w == vertex {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+        Control::Continue
+    }
+}
+
+/// Tarjan's algorithm, run on the iterative depth first search so that deep graphs do not overflow the stack.
+pub(crate) fn tarjan<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+) -> StronglyConnectedComponents {
+    let n = graph.num_v_labels();
+    let mut tarjan = Tarjan {
+        index: vec![usize::MAX; n],
+        lowlink: vec![usize::MAX; n],
+        on_stack: vec![false; n],
+        stack: vec![],
+        path: vec![],
+        components: vec![],
+    };
+    depth_first_visit(graph, graph.vertex_iterator(), &mut tarjan);
+
+    // Tarjan's algorithm completes sink components first
+    let mut components = tarjan.components;
+    components.reverse();
+    StronglyConnectedComponents::from_components(components, n)
+}
+
+/// The graph with every edge reversed, stored as adjacency lists.
+struct Reversed {
+    vertices: Vec<usize>,
+    in_neighbors: Vec<Vec<usize>>,
+}
+
+impl ViewCombinator for Reversed {
+    type VertexLabel = usize;
+
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        self.vertices.iter().copied()
+    }
+
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.in_neighbors
+            .iter()
+            .enumerate()
+            .flat_map(|(v, neigh)| neigh.iter().map(move |&u| (v, u)))
+    }
+
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> Option<impl Iterator<Item = usize> + 'a> {
+        self.in_neighbors
+            .get(vertex)
+            .map(|neigh| neigh.iter().copied())
+    }
+}
+
+impl ExactCombinator for Reversed {
+    fn num_v_labels(&self) -> usize {
+        self.in_neighbors.len()
+    }
+}
+
+/// Kosaraju's algorithm.
+///
+/// A post-order traversal of the graph is followed by a traversal of the reversed graph in reverse post-order,
+/// each tree of which is a component.
+pub(crate) fn kosaraju<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+) -> StronglyConnectedComponents {
+    let n = graph.num_v_labels();
+    let finished = DFSFullTraversal::<G, true>::new(graph)
+        .map(|node| node.vertex)
+        .collect::<Vec<_>>();
+
+    let mut in_neighbors = vec![vec![]; n];
+    for (u, v) in graph.edge_iterator() {
+        in_neighbors[v].push(u);
+    }
+    let reversed = Reversed {
+        vertices: graph.vertex_iterator().collect(),
+        in_neighbors,
+    };
+
+    let mut components: Vec<Vec<usize>> = vec![];
+    for node in
+        DFSFullTraversal::<_, false>::with_vertex_order(&reversed, finished.into_iter().rev())
+    {
+        if node.depth == 0 {
+            components.push(vec![]);
+        }
+        components.last_mut().unwrap().push(node.vertex);
+    }
+    StronglyConnectedComponents::from_components(components, n)
+}
+
+/// The condensation of a graph, in which each strongly connected component is contracted to a single vertex.
+///
+/// Vertex `i` is the `i`-th component of [`StronglyConnectedComponents`], so the labels form a topological order.
+/// Parallel edges between components are merged.
+pub struct Condensation {
+    pub components: StronglyConnectedComponents,
+    pub(crate) out_neighbors: Vec<Vec<usize>>,
+}
+
+impl Condensation {
+    pub(crate) fn build<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+        graph: &G,
+        components: StronglyConnectedComponents,
+    ) -> Self {
+        let mut out_neighbors = vec![vec![]; components.count()];
+        // The last source component which added each target, to skip parallel edges
+        let mut added_by = vec![usize::MAX; components.count()];
+        for (i, component) in components.components.iter().enumerate() {
+            for &u in component {
+                for v in graph.neighbor_iterator(u).unwrap() {
+                    let j = components.component_of[v];
+                    if j != i && added_by[j] != i {
+                        added_by[j] = i;
+                        out_neighbors[i].push(j);
+                    }
+                }
+            }
+        }
+        Self {
+            components,
+            out_neighbors,
+        }
+    }
+
+    /// Returns the members of the component at `vertex`.
+    pub fn members(&self, vertex: usize) -> &[usize] {
+        &self.components.components[vertex]
+    }
+}
+
+impl ViewCombinator for Condensation {
+    type VertexLabel = usize;
+
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        0..self.out_neighbors.len()
+    }
+
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.out_neighbors
+            .iter()
+            .enumerate()
+            .flat_map(|(u, neigh)| neigh.iter().map(move |&v| (u, v)))
+    }
+
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> Option<impl Iterator<Item = usize> + 'a> {
+        self.out_neighbors
+            .get(vertex)
+            .map(|neigh| neigh.iter().copied())
+    }
+}
+
+impl ExactCombinator for Condensation {
+    fn num_v_labels(&self) -> usize {
+        self.out_neighbors.len()
+    }
+}
+
+/// A directed cycle, reported when a topological order was requested for a graph which is not acyclic.
+///
+/// `cycle` lists the vertices of the cycle in the order in which its edges are traversed,
+/// with the edge from the last vertex back to the first closing it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectedCycle {
+    pub cycle: Vec<usize>,
+}
+
+impl Display for DirectedCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "graph contains a cycle through {:?}", self.cycle)
+    }
+}
+
+impl Error for DirectedCycle {}
+
+/// Orders the vertices so that every edge leads forward, using reverse post-order.
+pub(crate) fn topological_sort<G: ViewCombinator<VertexLabel = usize> + ExactCombinator>(
+    graph: &G,
+) -> Result<Vec<usize>, DirectedCycle> {
+    const UNVISITED: u8 = 0;
+    const ACTIVE: u8 = 1;
+    const FINISHED: u8 = 2;
+
+    let mut state = vec![UNVISITED; graph.num_v_labels()];
+    let mut order = vec![];
+
+    for root in graph.vertex_iterator() {
+        if state[root] != UNVISITED {
+            continue;
+        }
+        state[root] = ACTIVE;
+        let mut frames = vec![(root, graph.neighbor_iterator(root).unwrap())];
+
+        while let Some((v, neighbors)) = frames.last_mut() {
+            let v = *v;
+            match neighbors.next() {
+                Some(w) if state[w] == UNVISITED => {
+                    state[w] = ACTIVE;
+                    frames.push((w, graph.neighbor_iterator(w).unwrap()));
+                }
+                Some(w) if state[w] == ACTIVE => {
+                    // The active frames form a path from the root to `v`, which `w` lies on
+                    let start = frames.iter().position(|&(u, _)| u == w).unwrap();
+                    return Err(DirectedCycle {
+                        cycle: frames[start..].iter().map(|&(u, _)| u).collect(),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    state[v] = FINISHED;
+                    order.push(v);
+                    frames.pop();
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
@@ -0,0 +1,154 @@
+use squareknot_graph::{ExactCombinator, ViewCombinator};
+
+/// Controls how a visited depth first search proceeds after a callback.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Control {
+    #[default]
+    Continue,
+    /// From [`DfsVisitor::discover_vertex`], skips the neighbors of the vertex.
+    /// From [`DfsVisitor::tree_edge`], skips the edge, leaving its target undiscovered.
+    /// Elsewhere, this is the same as [`Control::Continue`].
+    Prune,
+    /// Stops the search immediately.
+    Break,
+}
+
+/// Callbacks for a depth first search.
+///
+/// Discovery and finish times share one counter, so a vertex `u` is an ancestor of `v` exactly when
+/// `u` is discovered before `v` and finished after it.
+///
+/// Edges are classified relative to the search tree. For an undirected graph each edge is seen from both ends,
+/// so every tree edge is also reported as a back edge from the child, and every back edge is also reported as a
+/// forward edge from the ancestor.
+pub trait DfsVisitor {
+    fn discover_vertex(&mut self, _vertex: usize, _time: usize) -> Control {
+        Control::Continue
+    }
+
+    /// Called for an edge to an undiscovered vertex, before the vertex is discovered.
+    fn tree_edge(&mut self, _u: usize, _v: usize) -> Control {
+        Control::Continue
+    }
+
+    /// Called for an edge to a vertex which has been discovered, but not finished, i.e. an ancestor.
+    fn back_edge(&mut self, _u: usize, _v: usize) -> Control {
+        Control::Continue
+    }
+
+    /// Called for an edge to a finished vertex.
+    /// This is a forward edge if `u` was discovered before `v`, and a cross edge otherwise.
+    fn forward_or_cross_edge(&mut self, _u: usize, _v: usize) -> Control {
+        Control::Continue
+    }
+
+    fn finish_vertex(&mut self, _vertex: usize, _time: usize) -> Control {
+        Control::Continue
+    }
+}
+
+/// A depth first search event, for visiting with a closure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DfsEvent {
+    Discover { vertex: usize, time: usize },
+    TreeEdge(usize, usize),
+    BackEdge(usize, usize),
+    ForwardOrCrossEdge(usize, usize),
+    Finish { vertex: usize, time: usize },
+}
+
+impl<F: FnMut(DfsEvent) -> Control> DfsVisitor for F {
+    fn discover_vertex(&mut self, vertex: usize, time: usize) -> Control {
+        self(DfsEvent::Discover { vertex, time })
+    }
+
+    fn tree_edge(&mut self, u: usize, v: usize) -> Control {
+        self(DfsEvent::TreeEdge(u, v))
+    }
+
+    fn back_edge(&mut self, u: usize, v: usize) -> Control {
+        self(DfsEvent::BackEdge(u, v))
+    }
+
+    fn forward_or_cross_edge(&mut self, u: usize, v: usize) -> Control {
+        self(DfsEvent::ForwardOrCrossEdge(u, v))
+    }
+
+    fn finish_vertex(&mut self, vertex: usize, time: usize) -> Control {
+        self(DfsEvent::Finish { vertex, time })
+    }
+}
+
+/// Runs a depth first search from each undiscovered vertex of `roots` in turn.
+/// Returns [`Control::Break`] if the visitor stopped the search, and [`Control::Continue`] otherwise.
+pub(crate) fn depth_first_visit<
+    G: ViewCombinator<VertexLabel = usize> + ExactCombinator,
+    V: DfsVisitor + ?Sized,
+>(
+    graph: &G,
+    roots: impl IntoIterator<Item = usize>,
+    visitor: &mut V,
+) -> Control {
+    let n = graph.num_v_labels();
+    let mut discovered = vec![false; n];
+    let mut finished = vec![false; n];
+    let mut time = 0;
+
+    for root in roots {
+        if discovered[root] {
+            continue;
+        }
+        discovered[root] = true;
+        let control = visitor.discover_vertex(root, time);
+        time += 1;
+        if control == Control::Break {
+            return Control::Break;
+        }
+        // Each frame holds a vertex, its remaining neighbors, and whether they should be explored
+        let mut frames = vec![(
+            root,
+            graph.neighbor_iterator(root).unwrap(),
+            control != Control::Prune,
+        )];
+
+        while let Some((v, neighbors, explore)) = frames.last_mut() {
+            let v = *v;
+            let next = if *explore { neighbors.next() } else { None };
+            let Some(w) = next else {
+                frames.pop();
+                finished[v] = true;
+                let control = visitor.finish_vertex(v, time);
+                time += 1;
+                if control == Control::Break {
+                    return Control::Break;
+                }
+                continue;
+            };
+
+            let control = if !discovered[w] {
+                match visitor.tree_edge(v, w) {
+                    Control::Continue => {
+                        discovered[w] = true;
+                        let control = visitor.discover_vertex(w, time);
+                        time += 1;
+                        frames.push((
+                            w,
+                            graph.neighbor_iterator(w).unwrap(),
+                            control != Control::Prune,
+                        ));
+                        control
+                    }
+                    control => control,
+                }
+            } else if !finished[w] {
+                visitor.back_edge(v, w)
+            } else {
+                visitor.forward_or_cross_edge(v, w)
+            };
+            if control == Control::Break {
+                return Control::Break;
+            }
+        }
+    }
+    Control::Continue
+}
@@ -1,16 +1,10 @@
-use squareknot::prelude::*;
+use squareknot::{constructors, prelude::*};
 
 // This example demonstrates some of the core features of the library
 fn main() {
     // Graph construction
-    let n = 7;
-    let mut graph: SimpleGraph = SimpleGraph::empty(n);
-
-    for u in 1..n {
-        for v in 0..u {
-            graph.add_edge(u, v);
-        }
-    }
+    // The `constructors` module provides common families of graphs, such as the complete graph K_7
+    let graph: SimpleGraph = constructors::complete(7);
 
     // This creates a `GraphView`, which is essentially an immutable reference to a graph that implements `ViewCombinator`
     let view = graph.view();
@@ -2,9 +2,11 @@
 
 use std::io::{stdout, Write};
 
-use rand::{seq::IndexedRandom, Rng};
+use rand::seq::IndexedRandom;
 use squareknot::{
+    constructors::random::gnp,
     graph::{AbstractGraph, SimpleGraph, ViewCombinator},
+    io::dot::DotWriter,
     prelude::TraversalView,
 };
 
@@ -40,25 +42,32 @@ fn find_kempe_chains(graph: &SimpleGraph, coloring: &[u8], colors: &[u8]) {
     }
 }
 
-fn main() {
-    let mut graph = SimpleGraph::empty(N);
+// Renders the subgraph induced by two colors as DOT, e.g. for `dot -Tsvg`.
+fn render_kempe_subgraph(graph: &SimpleGraph, coloring: &[u8], x: u8, y: u8) {
+    const NAMES: [&str; 5] = ["red", "green", "blue", "orange", "purple"];
+    let subgraph = graph
+        .view()
+        .filter_vertices(|&v| coloring[v] == x || coloring[v] == y);
+
+    DotWriter::new()
+        .name(format!("kempe_{x}_{y}"))
+        .vertex_color(|v: usize| NAMES[(coloring[v] - 1) as usize].to_string())
+        .write(&subgraph, stdout().lock())
+        .unwrap();
+}
 
+fn main() {
     let mut rng = rand::rng();
 
-    for u in 1..N {
-        for v in 0..u {
-            if rng.random::<f64>() < 0.2 {
-                graph.add_edge(u, v);
-            }
-        }
-    }
+    let graph: SimpleGraph = gnp(N, 0.2, &mut rng);
 
     let colors = [1, 2, 3, 4, 5];
     let mut coloring = vec![0; N];
 
-    for i in 0..N {
-        coloring[i] = *colors.choose(&mut rng).unwrap();
+    for c in coloring.iter_mut() {
+        *c = *colors.choose(&mut rng).unwrap();
     }
 
     find_kempe_chains(&graph, &coloring, &colors);
+    render_kempe_subgraph(&graph, &coloring, colors[0], colors[1]);
 }
@@ -0,0 +1,261 @@
+use std::f64::consts::PI;
+
+use squareknot::{constructors, prelude::*};
+
+fn assert_close(a: &[f64], b: &[f64]) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).abs() < 1e-9, "{a:?} != {b:?}");
+    }
+}
+
+/// The edges of two disjoint cliques on `n` vertices each.
+fn two_cliques(n: usize) -> impl Iterator<Item = (usize, usize)> {
+    [0, n].into_iter().flat_map(move |offset| {
+        (0..n).flat_map(move |v| (0..v).map(move |u| (offset + u, offset + v)))
+    })
+}
+
+#[test]
+fn path_matrices() {
+    let graph: SimpleGraph = constructors::path(4);
+    let view = graph.view();
+
+    let laplacian = view.laplacian_matrix();
+    #[rustfmt::skip]
+    let expected = [
+        1.0, -1.0, 0.0, 0.0,
+        -1.0, 2.0, -1.0, 0.0,
+        0.0, -1.0, 2.0, -1.0,
+        0.0, 0.0, -1.0, 1.0,
+    ];
+    assert_close(laplacian.transpose().as_slice(), &expected);
+    assert_eq!(
+        view.matrix(GraphMatrix::Degree) - view.adjacency_matrix(),
+        laplacian
+    );
+    assert_eq!(
+        view.matrix(GraphMatrix::Degree) + view.adjacency_matrix(),
+        view.matrix(GraphMatrix::SignlessLaplacian)
+    );
+
+    let incidence = view.matrix(GraphMatrix::Incidence);
+    let oriented = view.matrix(GraphMatrix::OrientedIncidence);
+    assert_eq!(incidence.shape(), (4, 3));
+    assert_eq!(&oriented * oriented.transpose(), laplacian);
+    assert_eq!(
+        &incidence * incidence.transpose(),
+        view.matrix(GraphMatrix::SignlessLaplacian)
+    );
+
+    let normalized = view.matrix(GraphMatrix::NormalizedLaplacian);
+    assert!((normalized[(0, 1)] + 0.5f64.sqrt()).abs() < 1e-12);
+    assert!((normalized[(1, 2)] + 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn spectra() {
+    let complete: SimpleGraph = constructors::complete(5);
+    assert_close(
+        &complete.view().eigenvalues(GraphMatrix::Laplacian),
+        &[0.0, 5.0, 5.0, 5.0, 5.0],
+    );
+    assert_close(
+        &complete.view().eigenvalues(GraphMatrix::Adjacency),
+        &[-1.0, -1.0, -1.0, -1.0, 4.0],
+    );
+
+    let cycle: SimpleGraph = constructors::cycle(7);
+    let mut expected = (0..7)
+        .map(|k| 2.0 - 2.0 * (2.0 * PI * k as f64 / 7.0).cos())
+        .collect::<Vec<_>>();
+    expected.sort_by(f64::total_cmp);
+    assert_close(&cycle.view().eigenvalues(GraphMatrix::Laplacian), &expected);
+    assert!((cycle.view().algebraic_connectivity() - expected[1]).abs() < 1e-9);
+
+    let star: SimpleGraph = constructors::star(4);
+    assert_close(
+        &star.view().eigenvalues(GraphMatrix::NormalizedLaplacian),
+        &[0.0, 1.0, 1.0, 1.0, 2.0],
+    );
+
+    let mut disconnected = SimpleGraph::empty(4);
+    disconnected.add_edge(0, 1);
+    disconnected.add_edge(2, 3);
+    assert!(disconnected.view().algebraic_connectivity().abs() < 1e-9);
+    assert_eq!(SimpleGraph::empty(1).view().algebraic_connectivity(), 0.0);
+    assert!(SimpleGraph::empty(1).view().fiedler_vector().is_none());
+}
+
+#[test]
+fn fiedler_bisection() {
+    // Two cliques on 0..5 and 7..12, joined by the path 4 - 5 - 6 - 7
+    let graph: SimpleGraph = constructors::barbell(5, 2);
+    let fiedler = graph.view().fiedler_vector().unwrap();
+    assert!((fiedler.norm() - 1.0).abs() < 1e-9);
+
+    let side = fiedler[0].signum();
+    assert!((0..5).all(|v| fiedler[v].signum() == side));
+    assert!((7..12).all(|v| fiedler[v].signum() == -side));
+
+    let laplacian = graph.view().laplacian_matrix();
+    let lambda = graph.view().algebraic_connectivity();
+    assert!((&laplacian * &fiedler - lambda * &fiedler).norm() < 1e-9);
+}
+
+#[test]
+fn sparse_matrices() {
+    let graph: SimpleGraph = constructors::barbell(4, 3);
+    let view = graph.view();
+    for kind in [
+        GraphMatrix::Adjacency,
+        GraphMatrix::Degree,
+        GraphMatrix::Laplacian,
+        GraphMatrix::NormalizedLaplacian,
+        GraphMatrix::SignlessLaplacian,
+        GraphMatrix::Incidence,
+        GraphMatrix::OrientedIncidence,
+    ] {
+        let sparse = view.sparse_matrix(kind);
+        let dense = view.matrix(kind);
+        assert_eq!(sparse.to_dense(), dense);
+        assert_eq!(sparse.nnz(), dense.iter().filter(|&&x| x != 0.0).count());
+
+        let x = nalgebra::DVector::from_fn(sparse.ncols(), |i, _| i as f64 - 2.5);
+        assert!((sparse.mul_vector(&x) - &dense * &x).norm() < 1e-12);
+    }
+
+    let laplacian = view.sparse_matrix(GraphMatrix::Laplacian);
+    assert_eq!(laplacian.get(0, 0), 3.0);
+    assert_eq!(laplacian.get(0, 10), 0.0);
+    assert_eq!(laplacian.row(4).count(), 3);
+
+    let summed = SparseMatrix::from_triplets(2, 3, [(1, 2, 1.0), (0, 0, 2.0), (1, 2, 0.5)]);
+    assert_eq!(
+        summed.triplet_iter().collect::<Vec<_>>(),
+        vec![(0, 0, 2.0), (1, 2, 1.5)]
+    );
+}
+
+#[test]
+fn filtered_matrices() {
+    // Removing the middle of a path leaves two edges
+    let graph: SimpleGraph = constructors::path(5);
+    let view = graph.view().filter_vertices(|&v| v != 2);
+    assert_eq!(view.matrix_vertices(), vec![0, 1, 3, 4]);
+
+    let adjacency = view.adjacency_matrix();
+    assert_eq!(adjacency.shape(), (4, 4));
+    assert_eq!(
+        (adjacency[(0, 1)], adjacency[(1, 2)], adjacency[(2, 3)]),
+        (1.0, 0.0, 1.0)
+    );
+    assert_eq!(view.matrix(GraphMatrix::Incidence).ncols(), 2);
+    assert!(view.algebraic_connectivity().abs() < 1e-9);
+
+    // A one-way arc gets an incidence column oriented along it
+    let mut digraph = SimpleDiGraph::empty(3);
+    digraph.add_edge(2, 0);
+    digraph.add_edge(0, 1);
+    digraph.add_edge(1, 0);
+    let oriented = digraph.view().matrix(GraphMatrix::OrientedIncidence);
+    assert_eq!(oriented.ncols(), 2);
+    assert_eq!(oriented.column(1).as_slice(), &[-1.0, 0.0, 1.0]);
+}
+
+#[test]
+fn spanning_tree_counts() {
+    // Cayley's formula
+    for n in [1, 2, 5, 30] {
+        let complete: SimpleGraph = constructors::complete(n);
+        let expected = BigUint::from(n).pow(n.saturating_sub(2) as u32);
+        assert_eq!(complete.view().spanning_tree_count(), expected);
+    }
+    let complete: SimpleGraph = constructors::complete(30);
+    assert!((complete.view().log_spanning_tree_count() - 28.0 * 30f64.ln()).abs() < 1e-6);
+
+    let petersen: SimpleGraph = constructors::petersen();
+    assert_eq!(petersen.view().spanning_tree_count(), 2000u32.into());
+    assert!((petersen.view().log_spanning_tree_count() - 2000f64.ln()).abs() < 1e-9);
+    let cube: SimpleGraph = constructors::hypercube(3);
+    assert_eq!(cube.view().spanning_tree_count(), 384u32.into());
+    let cycle: SimpleGraph = constructors::cycle(11);
+    assert_eq!(cycle.view().spanning_tree_count(), 11u32.into());
+
+    let mut disconnected = SimpleGraph::empty(4);
+    disconnected.add_edge(0, 1);
+    disconnected.add_edge(2, 3);
+    assert_eq!(disconnected.view().spanning_tree_count(), 0u32.into());
+    assert_eq!(
+        disconnected.view().log_spanning_tree_count(),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        SimpleGraph::empty(0).view().spanning_tree_count(),
+        0u32.into()
+    );
+
+    // Two disjoint copies of K13, large enough for rounding to hide the disconnection from a factorization
+    let mut cliques = SimpleGraph::empty(26);
+    for (u, v) in two_cliques(13) {
+        cliques.add_edge(u, v);
+    }
+    assert_eq!(cliques.view().spanning_tree_count(), 0u32.into());
+    assert_eq!(cliques.view().log_spanning_tree_count(), f64::NEG_INFINITY);
+
+    // Only the vertices kept by a filter are spanned
+    let wheel: SimpleGraph = constructors::wheel(6);
+    let rim = wheel.view().filter_vertices(|&v| v != 0);
+    assert_eq!(rim.spanning_tree_count(), 6u32.into());
+}
+
+#[test]
+fn arborescence_counts() {
+    let mut complete = SimpleDiGraph::empty(6);
+    for u in 0..6 {
+        for v in 0..6 {
+            if u != v {
+                complete.add_edge(u, v);
+            }
+        }
+    }
+    for root in [0, 5] {
+        assert_eq!(
+            complete.view().spanning_arborescence_count(root),
+            1296u32.into()
+        );
+        let log = complete.view().log_spanning_arborescence_count(root);
+        assert!((log - 1296f64.ln()).abs() < 1e-9);
+    }
+
+    // A directed path is its own arborescence from its start, and has none from anywhere else
+    let mut path = SimpleDiGraph::empty(4);
+    for v in 1..4 {
+        path.add_edge(v - 1, v);
+    }
+    assert_eq!(path.view().spanning_arborescence_count(0), 1u32.into());
+    assert_eq!(path.view().spanning_arborescence_count(1), 0u32.into());
+    assert_eq!(
+        path.view().log_spanning_arborescence_count(2),
+        f64::NEG_INFINITY
+    );
+
+    // Two routes from 0 to 3, and 3 reaches 1 and 2 either way
+    let mut diamond = SimpleDiGraph::empty(4);
+    for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 1), (3, 2)] {
+        diamond.add_edge(u, v);
+    }
+    assert_eq!(diamond.view().spanning_arborescence_count(0), 4u32.into());
+
+    // Two complete digraphs on 20 vertices, only one of which is reachable from the root
+    let mut cliques = SimpleDiGraph::empty(40);
+    for (u, v) in two_cliques(20) {
+        cliques.add_edge(u, v);
+        cliques.add_edge(v, u);
+    }
+    assert_eq!(cliques.view().spanning_arborescence_count(0), 0u32.into());
+    assert_eq!(
+        cliques.view().log_spanning_arborescence_count(0),
+        f64::NEG_INFINITY
+    );
+}
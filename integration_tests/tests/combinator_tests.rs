@@ -24,4 +24,158 @@ fn quotient_map() {
     assert!(r.contains(&(2, 4)) || r.contains(&(4, 2)));
     assert!(!r.contains(&(0, 0)));
     assert!(!r.contains(&(2, 2)));
-}
\ No newline at end of file
+}
+
+#[test]
+fn materialize_views() {
+    // A 6x6 grid with every third vertex removed
+    let grid: SimpleGraph = squareknot::constructors::grid(6, 6);
+    let filtered = grid.view().filter_vertices(|&v| v % 3 != 2);
+    let (graph, labels) = filtered.materialize();
+    assert_eq!(graph.num_v_labels(), 24);
+    assert_eq!(labels.len(), 24);
+    assert_eq!(
+        graph.size(),
+        filtered.edge_iterator().filter(|(u, v)| u < v).count()
+    );
+
+    // Traversals of the owned graph translate back to the view
+    let mut lazy = filtered
+        .bfs(0)
+        .map(|node| (node.vertex, node.depth))
+        .collect::<Vec<_>>();
+    let mut owned = graph
+        .view()
+        .bfs(labels.index(&0).unwrap())
+        .map(|node| (labels.label(node.vertex).unwrap(), node.depth))
+        .collect::<Vec<_>>();
+    lazy.sort();
+    owned.sort();
+    assert_eq!(lazy, owned);
+
+    // Labels need not be integers
+    let coordinates = grid.view().map_vertices(|&v| (v / 6, v % 6));
+    let (graph, labels) = coordinates.materialize();
+    assert_eq!((graph.order(), graph.size()), (36, 60));
+    for (i, &(r, c)) in labels.labels().iter().enumerate() {
+        assert_eq!(labels.index(&(r, c)), Some(i));
+        let degree = graph.neighbor_iterator(i).unwrap().count();
+        assert_eq!(
+            degree,
+            [r > 0, r < 5, c > 0, c < 5].iter().filter(|&&b| b).count()
+        );
+    }
+    assert_eq!(labels.index(&(6, 0)), None);
+    assert_eq!(labels.label(36), None);
+
+    // Directed views keep their orientation when materialized into a digraph
+    let mut digraph = SimpleDiGraph::empty(5);
+    for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)] {
+        digraph.add_edge(u, v);
+    }
+    let (path, labels) = digraph
+        .view()
+        .filter_vertices(|&v| v != 0)
+        .materialize_into::<SimpleDiGraph>();
+    assert_eq!(path.size(), 3);
+    assert_eq!(
+        path.view()
+            .topological_sort()
+            .unwrap()
+            .iter()
+            .map(|&v| labels.label(v).unwrap())
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn map_edges() {
+    let mut digraph = SimpleDiGraph::empty(4);
+    for (u, v) in [(0, 1), (1, 2), (2, 3), (0, 2)] {
+        digraph.add_edge(u, v);
+    }
+
+    // Reversing every arc agrees with the transpose
+    let reversed = digraph.view().map_edges(|&(u, v)| (v, u));
+    for v in 0..4 {
+        let mut a = reversed.neighbor_iterator(v).unwrap().collect::<Vec<_>>();
+        let mut b = digraph
+            .transpose_view()
+            .neighbor_iterator(v)
+            .unwrap()
+            .collect::<Vec<_>>();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+    assert_eq!(reversed.bfs(3).count(), 4);
+    assert!(reversed.neighbor_iterator(4).is_none());
+
+    // Rewire the edge {2, 3} of a path to {2, 0}, leaving 3 isolated
+    let path: SimpleGraph = squareknot::constructors::path(4);
+    let rewired = path.view().map_edges(|&e| match e {
+        (2, 3) => (2, 0),
+        (3, 2) => (0, 2),
+        e => e,
+    });
+    assert_eq!(rewired.neighbor_iterator(3).unwrap().count(), 0);
+    let mut neighbors = rewired.neighbor_iterator(0).unwrap().collect::<Vec<_>>();
+    neighbors.sort();
+    assert_eq!(neighbors, vec![1, 2]);
+    assert_eq!(rewired.component_count(), 2);
+    assert!(rewired.perfect_elimination_ordering().is_ok());
+
+    // Edges mapped onto a single vertex are dropped
+    let collapsed = path
+        .view()
+        .map_edges(|&(u, v)| (u, if v == 1 { 0 } else { v }));
+    assert!(!collapsed.edge_iterator().any(|(u, v)| u == v));
+    assert_eq!(collapsed.neighbor_iterator(0).unwrap().count(), 0);
+
+    // Edges mapped outside the view are dropped, so traversals stay within its labels
+    let shifted = path.view().map_edges(|&(u, v)| (u, v + 1));
+    assert!(shifted.edge_iterator().all(|(_, v)| v < 4));
+    assert_eq!(shifted.neighbor_iterator(2).unwrap().count(), 0);
+    assert_eq!(shifted.bfs(0).count(), 2);
+    assert_eq!(
+        shifted.neighbor_iterator(1).unwrap().collect::<Vec<_>>(),
+        vec![3]
+    );
+    assert!(shifted.neighbor_iterator(4).is_none());
+    let filtered = path
+        .view()
+        .filter_vertices(|&v| v != 3)
+        .map_edges(|&(u, v)| (u, v + 1));
+    assert_eq!(filtered.edge_iterator().collect::<Vec<_>>(), vec![(0, 2)]);
+    assert_eq!(filtered.neighbor_iterator(1).unwrap().count(), 0);
+
+    // Weights follow their edges
+    let mut weighted: SimpleWeightedDiGraph<u32> = SimpleWeightedDiGraph::empty(3);
+    weighted.add_weighted_edge(0, 1, 5);
+    weighted.add_weighted_edge(1, 2, 7);
+    let shifted = weighted
+        .view()
+        .map_weighted_edges(|&(u, v)| (u, (v + 1) % 3));
+    let mut arcs = shifted.weighted_edge_iterator().collect::<Vec<_>>();
+    arcs.sort();
+    assert_eq!(arcs, vec![(0, 2, 5), (1, 0, 7)]);
+    assert_eq!(
+        shifted
+            .weighted_neighbor_iterator(1)
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![(0, 7)]
+    );
+
+    // Shortest paths over reversed weighted arcs agree with the transpose
+    let reversed = weighted.view().map_weighted_edges(|&(u, v)| (v, u));
+    let transpose = weighted.transpose_view();
+    for v in 0..3 {
+        assert_eq!(
+            reversed.dijkstra(2).distance(v),
+            transpose.dijkstra(2).distance(v)
+        );
+    }
+    assert_eq!(reversed.dijkstra(2).distance(0), Some(12));
+}
@@ -0,0 +1,239 @@
+use rand::{rngs::StdRng, SeedableRng};
+use squareknot::{
+    constructors::{random::*, *},
+    prelude::*,
+};
+
+fn degrees<G: AbstractGraph<VertexLabel = usize>>(graph: &G) -> Vec<usize> {
+    (0..graph.num_v_labels())
+        .map(|v| graph.neighbor_iterator(v).unwrap().count())
+        .collect()
+}
+
+fn is_regular(graph: &SimpleGraph, degree: usize) -> bool {
+    degrees(graph).iter().all(|&d| d == degree)
+}
+
+fn is_connected(graph: &SimpleGraph) -> bool {
+    graph.order() == 0 || graph.view().bfs(0).count() == graph.order()
+}
+
+#[test]
+fn complete_families() {
+    let k6: SimpleGraph = complete(6);
+    assert_eq!((k6.order(), k6.size()), (6, 15));
+    assert!(is_regular(&k6, 5));
+
+    let k34: SimpleGraph = complete_bipartite(3, 4);
+    assert_eq!(k34.size(), 12);
+    assert!(k34.has_edge(0, 3) && !k34.has_edge(0, 1) && !k34.has_edge(3, 6));
+
+    let k123: SimpleGraph = complete_multipartite(&[1, 2, 3]);
+    assert_eq!(k123.order(), 6);
+    assert_eq!(k123.size(), 2 + 3 + 6);
+    assert_eq!(degrees(&k123), vec![5, 4, 4, 3, 3, 3]);
+
+    let empty: SimpleGraph = complete(0);
+    assert_eq!((empty.order(), empty.size()), (0, 0));
+}
+
+#[test]
+fn paths_and_cycles() {
+    let p5: SimpleGraph = path(5);
+    assert_eq!(p5.size(), 4);
+    assert_eq!(degrees(&p5), vec![1, 2, 2, 2, 1]);
+
+    let c7: SimpleGraph = cycle(7);
+    assert_eq!(c7.size(), 7);
+    assert!(is_regular(&c7, 2) && is_connected(&c7));
+
+    let s4: SimpleGraph = star(4);
+    assert_eq!(degrees(&s4), vec![4, 1, 1, 1, 1]);
+
+    let w5: SimpleGraph = wheel(5);
+    assert_eq!(w5.size(), 10);
+    assert_eq!(degrees(&w5), vec![5, 3, 3, 3, 3, 3]);
+
+    let f3: SimpleGraph = friendship(3);
+    assert_eq!((f3.order(), f3.size()), (7, 9));
+    assert_eq!(degrees(&f3)[0], 6);
+}
+
+#[test]
+fn barbells_and_lollipops() {
+    let b: SimpleGraph = barbell(4, 2);
+    assert_eq!(b.order(), 10);
+    assert_eq!(b.size(), 6 + 6 + 3);
+    assert!(is_connected(&b));
+    assert!(b.has_edge(3, 4) && b.has_edge(5, 6));
+
+    let joined: SimpleGraph = barbell(3, 0);
+    assert_eq!(joined.size(), 7);
+    assert!(joined.has_edge(2, 3));
+
+    let l: SimpleGraph = lollipop(5, 3);
+    assert_eq!((l.order(), l.size()), (8, 13));
+    assert_eq!(degrees(&l)[7], 1);
+    assert!(is_connected(&l));
+}
+
+#[test]
+fn lattices() {
+    let g: SimpleGraph = grid(3, 4);
+    assert_eq!(g.size(), 3 * 3 + 2 * 4);
+    assert!(g.has_edge(5, 6) && g.has_edge(5, 9) && !g.has_edge(3, 4));
+
+    let t: SimpleGraph = torus(3, 5);
+    assert_eq!(t.size(), 30);
+    assert!(is_regular(&t, 4));
+
+    let q4: SimpleGraph = hypercube(4);
+    assert_eq!((q4.order(), q4.size()), (16, 32));
+    assert!(is_regular(&q4, 4) && is_connected(&q4));
+}
+
+#[test]
+fn symmetric_families() {
+    let p: SimpleGraph = petersen();
+    assert_eq!((p.order(), p.size()), (10, 15));
+    assert!(is_regular(&p, 3));
+    assert_eq!(p.view().diameter(), Some(2));
+
+    let kg: SimpleGraph = kneser(5, 2);
+    assert_eq!((kg.order(), kg.size()), (10, 15));
+    assert!(is_regular(&kg, 3));
+
+    let dodecahedron: SimpleGraph = generalized_petersen(10, 2);
+    assert_eq!((dodecahedron.order(), dodecahedron.size()), (20, 30));
+    assert!(is_regular(&dodecahedron, 3) && is_connected(&dodecahedron));
+
+    let c: SimpleGraph = circulant(8, &[1, 4, 7, 12]);
+    assert_eq!(c.size(), 8 + 4);
+    assert!(is_regular(&c, 3));
+}
+
+#[test]
+fn directed_targets() {
+    let c: SimpleDiGraph = cycle(4);
+    assert_eq!(c.edge_iterator().count(), 8);
+    assert!(c.has_edge(0, 3) && c.has_edge(3, 0));
+}
+
+#[test]
+#[should_panic]
+fn cycle_too_small() {
+    let _: SimpleGraph = cycle(2);
+}
+
+fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+fn sorted_edges(graph: &SimpleGraph) -> Vec<(usize, usize)> {
+    let mut edges = graph
+        .edge_iterator()
+        .filter(|&(u, v)| u < v)
+        .collect::<Vec<_>>();
+    edges.sort();
+    edges
+}
+
+#[test]
+fn gnp_density_and_seeding() {
+    let a: SimpleGraph = gnp(2000, 0.005, &mut rng(1));
+    let b: SimpleGraph = gnp(2000, 0.005, &mut rng(1));
+    assert_eq!(sorted_edges(&a), sorted_edges(&b));
+
+    // The expected number of edges is 9995, with a standard deviation of about 100
+    assert!((9500..10500).contains(&a.size()), "{}", a.size());
+
+    let none: SimpleGraph = gnp(50, 0.0, &mut rng(2));
+    assert_eq!(none.size(), 0);
+    let all: SimpleGraph = gnp(50, 1.0, &mut rng(2));
+    assert_eq!(all.size(), 50 * 49 / 2);
+}
+
+#[test]
+fn gnm_edge_counts() {
+    for m in [0, 10, 400, 1225] {
+        let g: SimpleGraph = gnm(50, m, &mut rng(m as u64));
+        assert_eq!(g.size(), m);
+    }
+}
+
+#[test]
+fn preferential_attachment() {
+    let g: SimpleGraph = barabasi_albert(500, 3, &mut rng(3));
+    assert_eq!(g.size(), 3 * 497);
+    assert!(is_connected(&g));
+    assert!(degrees(&g)[3..].iter().all(|&d| d >= 3));
+}
+
+#[test]
+fn small_world() {
+    let ring: SimpleGraph = watts_strogatz(30, 4, 0.0, &mut rng(4));
+    assert!(is_regular(&ring, 4));
+    assert!(ring.has_edge(0, 29) && ring.has_edge(0, 28));
+
+    let rewired: SimpleGraph = watts_strogatz(30, 4, 0.5, &mut rng(4));
+    assert_eq!(rewired.size(), 60);
+    assert_ne!(sorted_edges(&rewired), sorted_edges(&ring));
+}
+
+#[test]
+fn regular_graphs() {
+    for (n, d) in [(10, 3), (50, 4), (101, 6), (8, 7), (200, 30)] {
+        let g: SimpleGraph = random_regular(n, d, &mut rng(n as u64));
+        assert!(is_regular(&g, d));
+        assert_eq!(g.size(), n * d / 2);
+    }
+}
+
+#[test]
+fn geometric_graphs() {
+    let (g, positions): (SimpleGraph, _) = random_geometric(300, 0.1, &mut rng(5));
+    assert_eq!(positions.len(), 300);
+    for v in 0..300 {
+        for u in 0..v {
+            let [x, y] = positions[u];
+            let [z, w] = positions[v];
+            let close = (x - z).hypot(y - w) <= 0.1;
+            assert_eq!(g.has_edge(u, v), close);
+        }
+    }
+
+    // Fewer cells than `1 / radius`, which must not lose any edges
+    let (g, positions): (SimpleGraph, _) = random_geometric(2000, 0.03, &mut rng(6));
+    let close = (0..2000)
+        .flat_map(|v| (0..v).map(move |u| (u, v)))
+        .filter(|&(u, v)| {
+            let ([x, y], [z, w]) = (positions[u], positions[v]);
+            (x - z).hypot(y - w) <= 0.03
+        })
+        .count();
+    assert_eq!(g.size(), close);
+
+    // A tiny radius does not allocate a cell per `radius`
+    let (_, positions): (SimpleGraph, _) = random_geometric(100_000, 1e-6, &mut rng(7));
+    assert_eq!(positions.len(), 100_000);
+}
+
+#[test]
+fn block_models() {
+    let probabilities = vec![
+        vec![1.0, 0.0, 0.5],
+        vec![0.0, 1.0, 0.0],
+        vec![0.5, 0.0, 0.0],
+    ];
+    let g: SimpleGraph = stochastic_block_model(&[10, 5, 20], &probabilities, &mut rng(6));
+    assert_eq!(g.order(), 35);
+    assert!(g.has_edge(0, 9) && g.has_edge(10, 14));
+    assert!(!g.has_edge(0, 10) && !g.has_edge(15, 16));
+
+    let between = sorted_edges(&g)
+        .into_iter()
+        .filter(|&(u, v)| u < 10 && v >= 15)
+        .count();
+    assert_eq!(g.size(), 45 + 10 + between);
+    assert!((60..140).contains(&between), "{between}");
+}
@@ -0,0 +1,290 @@
+use squareknot::prelude::*;
+
+fn path_digraph(n: usize) -> SimpleDiGraph {
+    let mut graph = SimpleDiGraph::empty(n);
+    for u in 1..n {
+        graph.add_edge(u - 1, u);
+    }
+    graph
+}
+
+#[test]
+fn digraph_bookkeeping() {
+    let mut graph = path_digraph(5);
+    assert_eq!(graph.order(), 5);
+    assert_eq!(graph.size(), 4);
+
+    // Arcs are directed, so the reverse arc is distinct
+    assert!(graph.has_edge(0, 1));
+    assert!(!graph.has_edge(1, 0));
+    assert!(!graph.add_edge(0, 1));
+    assert!(graph.add_edge(1, 0));
+    assert_eq!(graph.size(), 5);
+
+    assert!(graph.rem_edge(1, 0));
+    assert!(!graph.rem_edge(1, 0));
+    assert_eq!(graph.size(), 4);
+    assert_eq!(graph.edge_iterator().count(), 4);
+
+    assert!(graph.add_labeled_vertex(6));
+    assert_eq!(graph.order(), 7);
+    assert_eq!(graph.num_v_labels(), 7);
+}
+
+#[test]
+fn digraph_neighbors() {
+    let mut graph = path_digraph(4);
+    graph.add_edge(3, 1);
+
+    let out = graph.out_neighbor_iterator(1).unwrap().collect::<Vec<_>>();
+    let mut inc = graph.in_neighbor_iterator(1).unwrap().collect::<Vec<_>>();
+    inc.sort();
+
+    assert_eq!(out, vec![2]);
+    assert_eq!(inc, vec![0, 3]);
+    assert_eq!(graph.in_degree(1), Some(2));
+    assert_eq!(graph.out_degree(3), Some(1));
+    assert!(graph.out_neighbor_iterator(10).is_none());
+}
+
+#[test]
+fn digraph_traversal() {
+    let graph = path_digraph(5);
+
+    // Traversals follow arcs forwards
+    assert_eq!(graph.view().bfs(0).count(), 5);
+    assert_eq!(graph.view().bfs(2).count(), 3);
+
+    // and backwards on the transpose
+    let reached = graph
+        .transpose_view()
+        .dfs(2)
+        .map(|n| n.vertex)
+        .collect::<Vec<_>>();
+    assert_eq!(reached, vec![2, 1, 0]);
+
+    let filtered = graph.view().filter_vertices(|&v| v != 2);
+    assert_eq!(filtered.bfs(0).count(), 2);
+}
+
+fn digraph(n: usize, arcs: &[(usize, usize)]) -> SimpleDiGraph {
+    let mut graph = SimpleDiGraph::empty(n);
+    for &(u, v) in arcs {
+        graph.add_edge(u, v);
+    }
+    graph
+}
+
+fn sorted_components(scc: &StronglyConnectedComponents) -> Vec<Vec<usize>> {
+    let mut components = scc
+        .components
+        .iter()
+        .map(|c| {
+            let mut c = c.clone();
+            c.sort();
+            c
+        })
+        .collect::<Vec<_>>();
+    components.sort();
+    components
+}
+
+/// Checks that every arc between components leads forward in the component order.
+fn assert_topological(graph: &SimpleDiGraph, scc: &StronglyConnectedComponents) {
+    for (u, v) in graph.edge_iterator() {
+        assert!(scc.component_of[u] <= scc.component_of[v]);
+    }
+}
+
+#[test]
+fn strongly_connected_components() {
+    // Two cycles joined by an arc, a sink, and an isolated vertex
+    let graph = digraph(
+        8,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (5, 6),
+        ],
+    );
+    let expected = vec![vec![0, 1, 2], vec![3, 4, 5], vec![6], vec![7]];
+
+    let tarjan = graph.view().strongly_connected_components();
+    let kosaraju = graph.view().kosaraju_components();
+    assert_eq!(sorted_components(&tarjan), expected);
+    assert_eq!(sorted_components(&kosaraju), expected);
+    assert_topological(&graph, &tarjan);
+    assert_topological(&graph, &kosaraju);
+
+    // Filtered vertices belong to no component
+    let filtered = graph.view().filter_vertices(|&v| v != 4);
+    let scc = filtered.strongly_connected_components();
+    assert_eq!(scc.count(), 5);
+    assert_eq!(scc.component_of[4], usize::MAX);
+
+    // Undirected graphs have one component per connected component
+    let mut undirected = SimpleGraph::empty(5);
+    undirected.add_edge(0, 1);
+    undirected.add_edge(2, 3);
+    assert_eq!(undirected.view().strongly_connected_components().count(), 3);
+}
+
+#[test]
+fn deep_components_do_not_overflow() {
+    let n = 200_000;
+    let mut graph = path_digraph(n);
+    graph.add_edge(n - 1, 0);
+    assert_eq!(graph.view().strongly_connected_components().count(), 1);
+    assert_eq!(graph.view().kosaraju_components().count(), 1);
+}
+
+#[test]
+fn condensation_is_acyclic() {
+    let graph = digraph(
+        7,
+        &[
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (0, 2),
+            (2, 3),
+            (3, 2),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (4, 0),
+        ],
+    );
+    let condensation = graph.view().condensation();
+    assert_eq!(condensation.num_v_labels(), 3);
+    assert_eq!(condensation.edge_iterator().count(), 2);
+
+    let order = condensation.topological_sort().unwrap();
+    assert_eq!(order, vec![0, 1, 2]);
+    let mut source = condensation.members(0).to_vec();
+    source.sort();
+    assert_eq!(source, vec![4, 5, 6]);
+}
+
+#[test]
+fn topological_sort() {
+    // A small build graph: each arc points from a dependency to its dependent
+    let graph = digraph(6, &[(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)]);
+    let order = graph.view().topological_sort().unwrap();
+    let mut position = [0; 6];
+    for (i, &v) in order.iter().enumerate() {
+        position[v] = i;
+    }
+    assert_eq!(order.len(), 6);
+    assert!(graph
+        .edge_iterator()
+        .all(|(u, v)| position[u] < position[v]));
+
+    let cyclic = digraph(5, &[(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)]);
+    let cycle = cyclic.view().topological_sort().unwrap_err().cycle;
+    assert_eq!(cycle.len(), 3);
+    for (i, &u) in cycle.iter().enumerate() {
+        assert!(cyclic.has_edge(u, cycle[(i + 1) % cycle.len()]));
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    discovered: Vec<(usize, usize)>,
+    finished: Vec<(usize, usize)>,
+    tree: Vec<(usize, usize)>,
+    back: Vec<(usize, usize)>,
+    forward_or_cross: Vec<(usize, usize)>,
+}
+
+impl DfsVisitor for Recorder {
+    fn discover_vertex(&mut self, vertex: usize, time: usize) -> Control {
+        self.discovered.push((vertex, time));
+        Control::Continue
+    }
+
+    fn tree_edge(&mut self, u: usize, v: usize) -> Control {
+        self.tree.push((u, v));
+        Control::Continue
+    }
+
+    fn back_edge(&mut self, u: usize, v: usize) -> Control {
+        self.back.push((u, v));
+        Control::Continue
+    }
+
+    fn forward_or_cross_edge(&mut self, u: usize, v: usize) -> Control {
+        self.forward_or_cross.push((u, v));
+        Control::Continue
+    }
+
+    fn finish_vertex(&mut self, vertex: usize, time: usize) -> Control {
+        self.finished.push((vertex, time));
+        Control::Continue
+    }
+}
+
+#[test]
+fn dfs_edge_classification() {
+    // 0 -> 1 -> 2 -> 0 is a cycle, 0 -> 2 is a forward edge and 3 -> 1 is a cross edge
+    let graph = digraph(4, &[(0, 1), (1, 2), (2, 0), (0, 2), (3, 1)]);
+    let mut recorder = Recorder::default();
+    assert_eq!(
+        graph.view().full_dfs_visit(&mut recorder),
+        Control::Continue
+    );
+
+    assert_eq!(recorder.tree, vec![(0, 1), (1, 2)]);
+    assert_eq!(recorder.back, vec![(2, 0)]);
+    assert_eq!(recorder.forward_or_cross, vec![(0, 2), (3, 1)]);
+    assert_eq!(recorder.discovered, vec![(0, 0), (1, 1), (2, 2), (3, 6)]);
+    assert_eq!(recorder.finished, vec![(2, 3), (1, 4), (0, 5), (3, 7)]);
+
+    // Only vertices reachable from the root are visited
+    let mut recorder = Recorder::default();
+    graph.view().dfs_visit(1, &mut recorder);
+    assert_eq!(recorder.discovered.len(), 3);
+}
+
+#[test]
+fn dfs_visit_control() {
+    let graph = digraph(6, &[(0, 1), (1, 2), (2, 3), (3, 1), (0, 4), (4, 5)]);
+
+    // Cycle detection with a closure, stopping at the first back edge
+    let mut back_edge = None;
+    let control = graph.view().full_dfs_visit(&mut |event| match event {
+        DfsEvent::BackEdge(u, v) => {
+            back_edge = Some((u, v));
+            Control::Break
+        }
+        _ => Control::Continue,
+    });
+    assert_eq!(control, Control::Break);
+    assert_eq!(back_edge, Some((3, 1)));
+
+    // Pruning at vertex 1 hides the cycle
+    let mut events = vec![];
+    let control = graph.view().dfs_visit(0, &mut |event| {
+        events.push(event);
+        match event {
+            DfsEvent::Discover { vertex: 1, .. } => Control::Prune,
+            DfsEvent::TreeEdge(4, 5) => Control::Prune,
+            _ => Control::Continue,
+        }
+    });
+    assert_eq!(control, Control::Continue);
+    assert!(!events.iter().any(|e| matches!(e, DfsEvent::BackEdge(..))));
+    let discovered = events
+        .iter()
+        .filter_map(|e| match e {
+            DfsEvent::Discover { vertex, .. } => Some(*vertex),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(discovered, vec![0, 1, 4]);
+}
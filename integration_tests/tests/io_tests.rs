@@ -0,0 +1,508 @@
+use squareknot::{
+    io::{
+        dimacs::{Dimacs, FakeDimacs},
+        dot::{Dot, DotGraph, DotWriter},
+        graph6::{Digraph6, Graph6, LineFormat, Sparse6},
+        GraphFormat, GraphFormatError, Position, DEFAULT_MAX_ORDER,
+    },
+    prelude::*,
+};
+
+fn sorted_edges<G: AbstractGraph<VertexLabel = usize>>(graph: &G) -> Vec<(usize, usize)> {
+    let mut edges = graph
+        .edge_iterator()
+        .filter(|&(u, v)| u < v)
+        .collect::<Vec<_>>();
+    edges.sort();
+    edges
+}
+
+#[test]
+fn dimacs_parse() {
+    let input = "c A triangle with a pendant vertex\nc\np edge 4 4\ne 1 2\ne 2 3\n\ne 3 1\ne 3 4\n";
+    let graph: SimpleGraph = Dimacs::parse_graph(input.as_bytes()).unwrap();
+
+    assert_eq!(graph.order(), 4);
+    assert_eq!(graph.size(), 4);
+    assert_eq!(sorted_edges(&graph), vec![(0, 1), (0, 2), (1, 2), (2, 3)]);
+}
+
+#[test]
+fn dimacs_round_trip() {
+    let mut graph = SimpleGraph::empty(6);
+    for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5)] {
+        graph.add_edge(u, v);
+    }
+
+    let mut buffer = vec![];
+    Dimacs::write_graph(&graph, &mut buffer).unwrap();
+    let text = String::from_utf8(buffer.clone()).unwrap();
+    assert!(text.starts_with("p edge 6 5\n"));
+    assert_eq!(text.lines().filter(|l| l.starts_with("e ")).count(), 5);
+
+    let parsed: SimpleGraph = Dimacs::parse_graph(buffer.as_slice()).unwrap();
+    assert_eq!(sorted_edges(&parsed), sorted_edges(&graph));
+
+    let mut buffer = vec![];
+    FakeDimacs::write_graph(&graph, &mut buffer).unwrap();
+    let parsed: SimpleGraph = FakeDimacs::parse_graph(buffer.as_slice()).unwrap();
+    assert_eq!(sorted_edges(&parsed), sorted_edges(&graph));
+}
+
+fn parse_error<F: GraphFormat<SimpleGraph>>(input: &str) -> GraphFormatError {
+    match F::parse_graph(input.as_bytes()) {
+        Err(e) => e,
+        Ok(_) => panic!("expected an error"),
+    }
+}
+
+fn parse_error_line(input: &str) -> usize {
+    parse_error::<Dimacs<_>>(input).position().unwrap().line
+}
+
+#[test]
+fn dimacs_errors() {
+    assert_eq!(parse_error_line("c no header\ne 1 2\n"), 2);
+    assert_eq!(parse_error_line("p edge 3 1\ne 1 4\n"), 2);
+    assert_eq!(parse_error_line("p edge 3 1\ne 0 1\n"), 2);
+    assert_eq!(parse_error_line("p edge 3 1\nc\ne 1 x\n"), 3);
+    assert_eq!(parse_error_line("p graph 3 1\n"), 1);
+    assert_eq!(parse_error_line("p edge 3 1\nx 1 2\n"), 2);
+
+    // The vertex count is checked before the graph is allocated
+    match parse_error::<Dimacs<_>>("c\np edge 99999999999 0\n") {
+        GraphFormatError::OrderTooLarge {
+            position, order, ..
+        } => {
+            assert_eq!(position, Position { line: 2, column: 8 });
+            assert_eq!(order, 99999999999);
+        }
+        e => panic!("unexpected error {e}"),
+    }
+    assert!(matches!(
+        parse_error::<FakeDimacs<_>>("99999999999 0\n"),
+        GraphFormatError::OrderTooLarge { .. }
+    ));
+    let graph: SimpleGraph =
+        Dimacs::parse_graph_with_max_order("p edge 3 1\ne 1 3\n".as_bytes(), 3).unwrap();
+    assert_eq!(graph.order(), 3);
+    assert!(matches!(
+        FakeDimacs::<SimpleGraph>::parse_graph_with_max_order("3 1\n0 2\n".as_bytes(), 2),
+        Err(GraphFormatError::OrderTooLarge {
+            order: 3,
+            max_order: 2,
+            ..
+        })
+    ));
+    assert_eq!(parse_error_line("c only comments\n"), 2);
+}
+
+#[test]
+fn error_kinds() {
+    match parse_error::<Dimacs<_>>("p edge 3 1\ne 1  x\n") {
+        GraphFormatError::MalformedToken {
+            position, token, ..
+        } => {
+            assert_eq!(position, Position { line: 2, column: 6 });
+            assert_eq!(token, "x");
+        }
+        e => panic!("unexpected error {e}"),
+    }
+
+    match parse_error::<Dimacs<_>>("p edge 3 1\ne 1 4\n") {
+        GraphFormatError::VertexOutOfRange {
+            position,
+            vertex,
+            num_vertices,
+        } => {
+            assert_eq!(position, Position { line: 2, column: 5 });
+            assert_eq!((vertex, num_vertices), (4, 3));
+        }
+        e => panic!("unexpected error {e}"),
+    }
+
+    match parse_error::<Dimacs<_>>("p edge 3 3\ne 1 2\ne 2 3\n") {
+        GraphFormatError::EdgeCountMismatch {
+            expected, found, ..
+        } => assert_eq!((expected, found), (3, 2)),
+        e => panic!("unexpected error {e}"),
+    }
+
+    assert!(matches!(
+        parse_error::<Dimacs<_>>("p edge 3 1\ne 1\n"),
+        GraphFormatError::MissingToken { .. }
+    ));
+    assert!(matches!(
+        parse_error::<Dimacs<_>>(""),
+        GraphFormatError::UnexpectedEof { .. }
+    ));
+}
+
+#[test]
+fn fake_dimacs_rejects_truncation() {
+    // A truncated file is no longer parsed as a smaller graph
+    match parse_error::<FakeDimacs<_>>("10 5\n0 1\n1 2\n2 3\n") {
+        GraphFormatError::EdgeCountMismatch {
+            position,
+            expected,
+            found,
+        } => {
+            assert_eq!(position.line, 1);
+            assert_eq!((expected, found), (5, 3));
+        }
+        e => panic!("unexpected error {e}"),
+    }
+
+    match parse_error::<FakeDimacs<_>>("10 2\n0 1\n1 2 x\n") {
+        GraphFormatError::MalformedToken { position, .. } => {
+            assert_eq!(position, Position { line: 3, column: 5 })
+        }
+        e => panic!("unexpected error {e}"),
+    }
+
+    assert!(matches!(
+        parse_error::<FakeDimacs<_>>("3 1\n0 3\n"),
+        GraphFormatError::VertexOutOfRange { .. }
+    ));
+    assert!(matches!(
+        parse_error::<FakeDimacs<_>>("\n"),
+        GraphFormatError::UnexpectedEof { .. }
+    ));
+}
+
+fn lcg(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    *state >> 33
+}
+
+fn random_graph(n: usize, seed: u64) -> SimpleGraph {
+    let mut state = seed;
+    let mut graph = SimpleGraph::empty(n);
+    for v in 1..n {
+        for u in 0..v {
+            if lcg(&mut state).is_multiple_of(4) {
+                graph.add_edge(u, v);
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn graph6_known_encodings() {
+    let petersen: SimpleGraph = Graph6::parse_graph(">>graph6<<IheA@GUAo\n".as_bytes()).unwrap();
+    assert_eq!(petersen.order(), 10);
+    assert_eq!(petersen.size(), 15);
+    assert!((0..10).all(|v| petersen.neighbor_iterator(v).unwrap().count() == 3));
+    assert_eq!(Graph6::encode(&petersen), "IheA@GUAo");
+
+    // The example from the sparse6 specification
+    let sparse: SimpleGraph = Sparse6::parse_graph(":Fa@x^\n".as_bytes()).unwrap();
+    assert_eq!(sorted_edges(&sparse), vec![(0, 1), (0, 2), (1, 2), (5, 6)]);
+    assert_eq!(Sparse6::encode(&sparse), ":Fa@x^");
+
+    // The example from the digraph6 specification
+    let directed: SimpleDiGraph = Digraph6::parse_graph("&DI?AO?\n".as_bytes()).unwrap();
+    let mut arcs = directed.edge_iterator().collect::<Vec<_>>();
+    arcs.sort();
+    assert_eq!(arcs, vec![(0, 2), (0, 4), (3, 1), (3, 4)]);
+    assert_eq!(Digraph6::encode(&directed), "&DI?AO?");
+}
+
+#[test]
+fn graph6_round_trips() {
+    for (n, seed) in [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (4, 4),
+        (8, 5),
+        (16, 6),
+        (63, 7),
+        (100, 8),
+    ] {
+        let graph = random_graph(n, seed);
+
+        let g6: SimpleGraph = Graph6::parse_line(&Graph6::encode(&graph), 1).unwrap();
+        assert_eq!(sorted_edges(&g6), sorted_edges(&graph));
+
+        let s6: SimpleGraph = Sparse6::parse_line(&Sparse6::encode(&graph), 1).unwrap();
+        assert_eq!(s6.order(), n);
+        assert_eq!(sorted_edges(&s6), sorted_edges(&graph));
+    }
+
+    // Vertex n - 2 has an edge but n - 1 does not, which needs special padding in sparse6
+    for n in [2, 4, 8, 16] {
+        let mut graph = SimpleGraph::empty(n);
+        if n > 2 {
+            graph.add_edge(0, n - 2);
+        }
+        let s6: SimpleGraph = Sparse6::parse_line(&Sparse6::encode(&graph), 1).unwrap();
+        assert_eq!(sorted_edges(&s6), sorted_edges(&graph));
+    }
+
+    let mut directed = SimpleDiGraph::empty(9);
+    for (u, v) in [(0, 1), (1, 0), (2, 8), (8, 3), (5, 4)] {
+        directed.add_edge(u, v);
+    }
+    let d6: SimpleDiGraph = Digraph6::parse_line(&Digraph6::encode(&directed), 1).unwrap();
+    let mut arcs = d6.edge_iterator().collect::<Vec<_>>();
+    arcs.sort();
+    assert_eq!(arcs, vec![(0, 1), (1, 0), (2, 8), (5, 4), (8, 3)]);
+}
+
+#[test]
+fn graph6_streams() {
+    let graphs = (0..20)
+        .map(|i| random_graph(i % 7 + 1, i as u64))
+        .collect::<Vec<_>>();
+
+    let mut buffer = vec![];
+    Graph6::write_graphs(&graphs, &mut buffer).unwrap();
+    buffer.extend_from_slice(b"\n");
+    let parsed = Graph6::graphs(buffer.as_slice())
+        .collect::<Result<Vec<SimpleGraph>, _>>()
+        .unwrap();
+    assert_eq!(parsed.len(), graphs.len());
+    for (a, b) in parsed.iter().zip(&graphs) {
+        assert_eq!(sorted_edges(a), sorted_edges(b));
+    }
+
+    let mut buffer = vec![];
+    Sparse6::write_graphs(&graphs, &mut buffer).unwrap();
+    let parsed = Sparse6::graphs(buffer.as_slice())
+        .collect::<Result<Vec<SimpleGraph>, _>>()
+        .unwrap();
+    assert_eq!(parsed.len(), graphs.len());
+
+    let mut lines = Graph6::<SimpleGraph>::graphs("Bw\nC~\nBw x\n".as_bytes());
+    assert_eq!(lines.next().unwrap().unwrap().size(), 3);
+    assert_eq!(lines.next().unwrap().unwrap().size(), 6);
+    match lines.next().unwrap() {
+        Err(GraphFormatError::Syntax { position, .. }) => {
+            assert_eq!(position, Position { line: 3, column: 3 })
+        }
+        _ => panic!("expected a syntax error"),
+    }
+    assert!(lines.next().is_none());
+}
+
+#[test]
+fn graph6_errors() {
+    assert!(matches!(
+        parse_error::<Graph6<_>>("C~~\n"),
+        GraphFormatError::Syntax { .. }
+    ));
+    assert!(matches!(
+        parse_error::<Graph6<_>>("E\n"),
+        GraphFormatError::MissingToken { .. }
+    ));
+    match parse_error::<Graph6<_>>("C \n") {
+        GraphFormatError::MalformedToken { position, .. } => {
+            assert_eq!(position, Position { line: 1, column: 2 })
+        }
+        e => panic!("unexpected error {e}"),
+    }
+    assert!(matches!(
+        parse_error::<Sparse6<_>>("Fa@x^\n"),
+        GraphFormatError::MalformedToken { .. }
+    ));
+    assert!(matches!(
+        parse_error::<Graph6<_>>(""),
+        GraphFormatError::UnexpectedEof { .. }
+    ));
+
+    // Hostile orders are rejected before any storage is allocated
+    assert!(matches!(
+        parse_error::<Graph6<_>>("~?~~~\n"),
+        GraphFormatError::MissingToken { .. }
+    ));
+    assert!(matches!(
+        parse_error::<Graph6<_>>("~~~~~~~~\n"),
+        GraphFormatError::OrderTooLarge { .. }
+    ));
+    assert!(matches!(
+        parse_error::<Digraph6<_>>("&~~~~~~~~\n"),
+        GraphFormatError::OrderTooLarge { .. }
+    ));
+}
+
+#[test]
+fn graph6_max_order() {
+    // Isolated vertices are free in sparse6, so only the maximum order bounds the allocation
+    match parse_error::<Sparse6<_>>(":~~?A????\n") {
+        GraphFormatError::OrderTooLarge {
+            position,
+            order,
+            max_order,
+        } => {
+            assert_eq!(position.line, 1);
+            assert_eq!(order, 2 << 24);
+            assert_eq!(max_order, DEFAULT_MAX_ORDER);
+        }
+        e => panic!("unexpected error {e}"),
+    }
+
+    let sparse: SimpleGraph =
+        Sparse6::parse_graph_with_max_order(":Fa@x^\n".as_bytes(), 7).unwrap();
+    assert_eq!(sparse.order(), 7);
+    assert!(matches!(
+        Sparse6::<SimpleGraph>::parse_line_with_max_order(":Fa@x^", 1, 6),
+        Err(GraphFormatError::OrderTooLarge { order: 7, .. })
+    ));
+    assert!(matches!(
+        Graph6::<SimpleGraph>::graphs_with_max_order("A_\nC~\n".as_bytes(), 2)
+            .nth(1)
+            .unwrap(),
+        Err(GraphFormatError::OrderTooLarge { order: 4, .. })
+    ));
+}
+
+#[test]
+fn dot_writer_views() {
+    let mut graph = SimpleGraph::empty(5);
+    for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)] {
+        graph.add_edge(u, v);
+    }
+    let coloring = [1, 2, 1, 2, 3];
+    let subgraph = graph
+        .view()
+        .filter_vertices(|&v| coloring[v] == 1 || coloring[v] == 2);
+
+    let mut buffer = vec![];
+    DotWriter::new()
+        .name("kempe chain")
+        .vertex_label(|v: usize| format!("v{v}"))
+        .vertex_color(|v| ["red", "blue"][coloring[v] - 1].to_string())
+        .edge_label(|u, v| format!("{}", u + v))
+        .write(&subgraph, &mut buffer)
+        .unwrap();
+    let text = String::from_utf8(buffer).unwrap();
+
+    assert!(text.starts_with("graph \"kempe chain\" {\n"));
+    assert!(text.contains("    3 [label=v3, color=blue];\n"));
+    assert!(!text.contains("    4"));
+    assert_eq!(text.matches(" -- ").count(), 4);
+
+    let parsed = DotGraph::parse_str(&text).unwrap();
+    assert_eq!(parsed.name.as_deref(), Some("kempe chain"));
+    assert_eq!(parsed.nodes.len(), 4);
+    assert_eq!(parsed.edges.len(), 4);
+    let v3 = parsed.node_index("3").unwrap();
+    assert_eq!(parsed.nodes[v3].attributes["color"], "blue");
+}
+
+#[test]
+fn dot_parse() {
+    let input = r#"
+        /* A small digraph */
+        strict digraph "G" {
+            rankdir = LR;
+            node [shape=box]
+            a -> b -> c [color=red, label="a \"label\""];
+            b:n -> {d e}
+            subgraph cluster_0 {
+                node [shape=circle];
+                f; a -> f
+            }
+            c [shape=point] // a comment
+            edge [weight=2] c -> a
+        }
+    "#;
+    let graph = DotGraph::parse_str(input).unwrap();
+    assert!(graph.directed && graph.strict);
+    assert_eq!(graph.name.as_deref(), Some("G"));
+    assert_eq!(graph.attributes["rankdir"], "LR");
+
+    let ids = graph
+        .nodes
+        .iter()
+        .map(|n| n.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["a", "b", "c", "d", "e", "f"]);
+    let node = |id| &graph.nodes[graph.node_index(id).unwrap()];
+    assert_eq!(node("a").attributes["shape"], "box");
+    assert_eq!(node("f").attributes["shape"], "circle");
+    assert_eq!(node("c").attributes["shape"], "point");
+
+    assert_eq!(graph.edges.len(), 6);
+    assert_eq!(graph.edges[1].attributes["label"], "a \"label\"");
+    assert_eq!(graph.edges[5].attributes["weight"], "2");
+    assert!(!graph.edges[0].attributes.contains_key("weight"));
+
+    let digraph: SimpleDiGraph = graph.to_graph();
+    let mut arcs = digraph.edge_iterator().collect::<Vec<_>>();
+    arcs.sort();
+    assert_eq!(arcs, vec![(0, 1), (0, 5), (1, 2), (1, 3), (1, 4), (2, 0)]);
+}
+
+#[test]
+fn dot_round_trip() {
+    let mut graph = SimpleGraph::empty(5);
+    for (u, v) in [(0, 1), (1, 2), (3, 1)] {
+        graph.add_edge(u, v);
+    }
+    let mut buffer = vec![];
+    Dot::write_graph(&graph, &mut buffer).unwrap();
+    assert!(buffer.starts_with(b"graph {"));
+    let parsed: SimpleGraph = Dot::parse_graph(buffer.as_slice()).unwrap();
+    assert_eq!(parsed.order(), 5);
+    assert_eq!(sorted_edges(&parsed), sorted_edges(&graph));
+
+    let mut directed = SimpleDiGraph::empty(3);
+    directed.add_edge(0, 1);
+    directed.add_edge(2, 1);
+    let mut buffer = vec![];
+    Dot::write_graph(&directed, &mut buffer).unwrap();
+    assert!(buffer.starts_with(b"digraph {"));
+    let parsed: SimpleDiGraph = Dot::parse_graph(buffer.as_slice()).unwrap();
+    assert_eq!(parsed.edge_iterator().count(), 2);
+}
+
+#[test]
+fn dot_escapes_backslashes() {
+    let mut graph = SimpleGraph::empty(2);
+    graph.add_edge(0, 1);
+    let mut buffer = vec![];
+    DotWriter::new()
+        .name("C:\\")
+        .vertex_label(|v: usize| format!("a\\{v}\\"))
+        .write(&graph.view(), &mut buffer)
+        .unwrap();
+    let text = String::from_utf8(buffer).unwrap();
+    assert!(text.contains(r#"[label="a\\0\\"]"#));
+
+    let parsed = DotGraph::parse_str(&text).unwrap();
+    assert_eq!(parsed.name.as_deref(), Some("C:\\"));
+    assert_eq!(parsed.nodes[0].attributes["label"], "a\\0\\");
+    assert_eq!(parsed.nodes[1].attributes["label"], "a\\1\\");
+    assert_eq!(parsed.edges.len(), 1);
+}
+
+#[test]
+fn dot_errors() {
+    assert!(matches!(
+        DotGraph::parse_str("graph { a -> b }"),
+        Err(GraphFormatError::Syntax { .. })
+    ));
+    match DotGraph::parse_str("digraph {\n  a -> b\n  c = \n}") {
+        Err(GraphFormatError::MalformedToken { position, .. }) => {
+            assert_eq!(position, Position { line: 4, column: 1 })
+        }
+        r => panic!("unexpected result {r:?}"),
+    }
+    assert!(matches!(
+        DotGraph::parse_str("graph { a -- b"),
+        Err(GraphFormatError::UnexpectedEof { .. })
+    ));
+    assert!(matches!(
+        DotGraph::parse_str("graph { a [label=\"x] }"),
+        Err(GraphFormatError::Syntax { .. })
+    ));
+    assert!(matches!(
+        DotGraph::parse_str("tree { }"),
+        Err(GraphFormatError::MalformedToken { .. })
+    ));
+}
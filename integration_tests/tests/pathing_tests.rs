@@ -0,0 +1,436 @@
+use squareknot::prelude::*;
+
+/// A small deterministic generator so that tests do not need `rand`.
+fn lcg(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    *state >> 33
+}
+
+fn random_weighted(n: usize, seed: u64) -> SimpleWeightedGraph<u64> {
+    let mut state = seed;
+    let mut graph = SimpleWeightedGraph::empty(n);
+    for u in 1..n {
+        for v in 0..u {
+            if lcg(&mut state).is_multiple_of(4) {
+                graph.add_weighted_edge(u, v, lcg(&mut state) % 20 + 1);
+            }
+        }
+    }
+    graph
+}
+
+fn floyd_warshall(graph: &SimpleWeightedGraph<u64>) -> Vec<Vec<Option<u64>>> {
+    let n = graph.num_v_labels();
+    let mut dist = vec![vec![None; n]; n];
+    for (v, row) in dist.iter_mut().enumerate() {
+        row[v] = Some(0);
+    }
+    for (u, v, w) in graph.weighted_edge_iterator() {
+        dist[u][v] = Some(w);
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+                    if dist[i][j].is_none_or(|d| a + b < d) {
+                        dist[i][j] = Some(a + b);
+                    }
+                }
+            }
+        }
+    }
+    dist
+}
+
+fn check_path(graph: &SimpleWeightedGraph<u64>, path: &WeightedPath<u64>, s: usize, t: usize) {
+    assert_eq!(path.vertices.first(), Some(&s));
+    assert_eq!(path.vertices.last(), Some(&t));
+    let cost: u64 = path
+        .vertices
+        .windows(2)
+        .map(|e| graph.edge_weight(e[0], e[1]).unwrap())
+        .sum();
+    assert_eq!(cost, path.cost);
+}
+
+#[test]
+fn weighted_shortest_paths_agree() {
+    for seed in 0..5 {
+        let graph = random_weighted(25, seed);
+        let expected = floyd_warshall(&graph);
+        let view = graph.view();
+
+        for (s, row) in expected.iter().enumerate() {
+            let tree = view.dijkstra(s);
+            for (t, &distance) in row.iter().enumerate() {
+                assert_eq!(tree.distance(t), distance);
+
+                let paths = [
+                    tree.path_to(t),
+                    view.dijkstra_path(s, t),
+                    view.astar(s, t, |_| 0),
+                    view.bidirectional_dijkstra(s, t),
+                ];
+                for path in paths {
+                    assert_eq!(path.as_ref().map(|p| p.cost), distance);
+                    if let Some(path) = path {
+                        check_path(&graph, &path, s, t);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn astar_on_grid() {
+    let side = 8;
+    let mut graph: SimpleWeightedGraph<u32> = SimpleWeightedGraph::empty(side * side);
+    for r in 0..side {
+        for c in 0..side {
+            let v = r * side + c;
+            if c + 1 < side {
+                graph.add_weighted_edge(v, v + 1, 1);
+            }
+            if r + 1 < side {
+                graph.add_weighted_edge(v, v + side, 1);
+            }
+        }
+    }
+    let target = side * side - 1;
+    let manhattan = |v: usize| ((side - 1 - v / side) + (side - 1 - v % side)) as u32;
+
+    let path = graph.view().astar(0, target, manhattan).unwrap();
+    assert_eq!(path.cost, 14);
+    assert_eq!(path.vertices.len(), 15);
+}
+
+#[test]
+fn route_around_closed_roads() {
+    let mut graph: SimpleWeightedGraph<f64> = SimpleWeightedGraph::empty(4);
+    graph.add_weighted_edge(0, 1, 1.0);
+    graph.add_weighted_edge(1, 3, 1.0);
+    graph.add_weighted_edge(0, 2, 2.5);
+    graph.add_weighted_edge(2, 3, 2.5);
+
+    let open = graph.view().dijkstra_path(0, 3).unwrap();
+    assert_eq!(open.vertices, vec![0, 1, 3]);
+
+    let closed = graph
+        .view()
+        .filter_edges(|&(u, v)| (u, v) != (1, 3) && (u, v) != (3, 1));
+    let detour = closed.bidirectional_dijkstra(0, 3).unwrap();
+    assert_eq!(detour.vertices, vec![0, 2, 3]);
+    assert_eq!(detour.cost, 5.0);
+
+    let unreachable = graph.view().filter_vertices(|&v| v == 0 || v == 3);
+    assert!(unreachable.dijkstra_path(0, 3).is_none());
+    assert!(unreachable.bidirectional_dijkstra(0, 3).is_none());
+}
+
+#[test]
+fn bidirectional_on_digraph() {
+    let mut graph: SimpleWeightedDiGraph<u32> = SimpleWeightedDiGraph::empty(4);
+    graph.add_weighted_edge(0, 1, 5);
+    graph.add_weighted_edge(1, 2, 5);
+    graph.add_weighted_edge(2, 3, 5);
+    graph.add_weighted_edge(3, 0, 1);
+
+    let forward = graph.view();
+    let reverse = graph.transpose_view();
+    let path = forward.bidirectional_dijkstra_with(&reverse, 0, 3).unwrap();
+    assert_eq!(path.vertices, vec![0, 1, 2, 3]);
+    assert_eq!(path.cost, 15);
+    assert_eq!(forward.dijkstra_path(3, 2).unwrap().cost, 11);
+}
+
+fn random_weighted_digraph(n: usize, seed: u64) -> SimpleWeightedDiGraph<i64> {
+    let mut state = seed;
+    let mut graph = SimpleWeightedDiGraph::empty(n);
+    for u in 0..n {
+        for v in 0..n {
+            // Only arcs from lower to higher labels may be negative, so there are no negative cycles
+            if u != v && lcg(&mut state).is_multiple_of(5) {
+                let w = (lcg(&mut state) % 30) as i64;
+                graph.add_weighted_edge(u, v, if u < v { w - 10 } else { w + 10 });
+            }
+        }
+    }
+    graph
+}
+
+fn floyd_warshall_directed(graph: &SimpleWeightedDiGraph<i64>) -> Vec<Vec<Option<i64>>> {
+    let n = graph.num_v_labels();
+    let mut dist = vec![vec![None; n]; n];
+    for (v, row) in dist.iter_mut().enumerate() {
+        row[v] = Some(0);
+    }
+    for (u, v, w) in graph.weighted_edge_iterator() {
+        dist[u][v] = Some(w);
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+                    if dist[i][j].is_none_or(|d| a + b < d) {
+                        dist[i][j] = Some(a + b);
+                    }
+                }
+            }
+        }
+    }
+    dist
+}
+
+#[test]
+fn negative_weights_agree() {
+    for seed in 0..5 {
+        let graph = random_weighted_digraph(20, seed);
+        let expected = floyd_warshall_directed(&graph);
+        let view = graph.view();
+
+        let all_pairs = view.johnson().unwrap();
+        for (s, row) in expected.iter().enumerate() {
+            let tree = view.bellman_ford(s).unwrap();
+            for (t, &distance) in row.iter().enumerate() {
+                assert_eq!(tree.distance(t), distance);
+                assert_eq!(all_pairs[s].distance(t), distance);
+
+                if let Some(path) = all_pairs[s].path_to(t) {
+                    let cost: i64 = path
+                        .vertices
+                        .windows(2)
+                        .map(|e| graph.edge_weight(e[0], e[1]).unwrap())
+                        .sum();
+                    assert_eq!(cost, path.cost);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn negative_cycle_witness() {
+    let mut graph: SimpleWeightedDiGraph<i32> = SimpleWeightedDiGraph::empty(6);
+    graph.add_weighted_edge(0, 1, 2);
+    graph.add_weighted_edge(1, 2, 3);
+    graph.add_weighted_edge(2, 3, -4);
+    graph.add_weighted_edge(3, 1, -1);
+    graph.add_weighted_edge(3, 4, 1);
+    graph.add_weighted_edge(5, 0, 1);
+
+    for result in [
+        graph.view().bellman_ford(0).map(|_| ()),
+        graph.view().johnson().map(|_| ()),
+    ] {
+        let mut cycle = result.unwrap_err().cycle;
+        let total: i32 = (0..cycle.len())
+            .map(|i| {
+                graph
+                    .edge_weight(cycle[i], cycle[(i + 1) % cycle.len()])
+                    .unwrap()
+            })
+            .sum();
+        assert!(total < 0);
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2, 3]);
+    }
+
+    // The cycle is not reachable from 4
+    assert!(graph.view().bellman_ford(4).is_ok());
+}
+
+fn path_graph(n: usize) -> SimpleGraph {
+    let mut graph = SimpleGraph::empty(n);
+    for u in 1..n {
+        graph.add_edge(u - 1, u);
+    }
+    graph
+}
+
+#[test]
+fn all_pairs_rows() {
+    let graph = path_graph(5);
+    let view = graph.view();
+    let mut apsp = view.all_pairs_shortest_paths();
+
+    let first = apsp.next().unwrap();
+    assert_eq!(first.source, 0);
+    assert_eq!(first.distances, vec![0, 1, 2, 3, 4]);
+    assert_eq!(apsp.count(), 4);
+
+    let subgraph = graph.view().filter_vertices(|&v| v != 2);
+    let rows = subgraph.all_pairs_shortest_paths().collect::<Vec<_>>();
+    assert_eq!(rows.len(), 4);
+    assert_eq!(
+        rows[0].distances,
+        vec![0, 1, usize::MAX, usize::MAX, usize::MAX]
+    );
+}
+
+#[test]
+fn distance_metrics() {
+    let graph = path_graph(5);
+    let view = graph.view();
+    assert_eq!(view.eccentricities(), vec![4, 3, 2, 3, 4]);
+    assert_eq!(view.radius(), Some(2));
+    assert_eq!(view.diameter(), Some(4));
+    assert_eq!(view.center(), vec![2]);
+    assert_eq!(view.periphery(), vec![0, 4]);
+    assert_eq!(view.wiener_index(), Some(20));
+
+    // The Petersen graph
+    let mut petersen = SimpleGraph::empty(10);
+    for i in 0..5 {
+        petersen.add_edge(i, (i + 1) % 5);
+        petersen.add_edge(i, i + 5);
+        petersen.add_edge(i + 5, (i + 2) % 5 + 5);
+    }
+    let view = petersen.view();
+    assert_eq!(view.radius(), Some(2));
+    assert_eq!(view.diameter(), Some(2));
+    assert_eq!(view.center().len(), 10);
+    assert_eq!(view.wiener_index(), Some(75));
+
+    let split = graph.view().filter_edges(|&(u, v)| u + v != 5);
+    assert_eq!(split.radius(), None);
+    assert_eq!(split.diameter(), None);
+    assert!(split.center().is_empty());
+    assert_eq!(split.wiener_index(), None);
+}
+
+/// A view which lists every vertex of the graph twice.
+struct Repeated<'a>(GraphView<'a, SimpleGraph>);
+
+impl ViewCombinator for Repeated<'_> {
+    type VertexLabel = usize;
+
+    fn vertex_iterator<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        self.0.vertex_iterator().chain(self.0.vertex_iterator())
+    }
+
+    fn edge_iterator<'a>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.0.edge_iterator()
+    }
+
+    fn neighbor_iterator<'a>(&'a self, vertex: usize) -> Option<impl Iterator<Item = usize> + 'a> {
+        self.0.neighbor_iterator(vertex)
+    }
+}
+
+impl ExactCombinator for Repeated<'_> {
+    fn num_v_labels(&self) -> usize {
+        self.0.num_v_labels()
+    }
+}
+
+#[test]
+fn distance_metrics_repeated_vertices() {
+    let graph = path_graph(5);
+    let view = Repeated(graph.view());
+    assert_eq!(view.all_pairs_shortest_paths().count(), 5);
+    assert_eq!(view.eccentricities(), vec![4, 3, 2, 3, 4]);
+    assert_eq!(view.diameter(), Some(4));
+    assert_eq!(view.wiener_index(), Some(20));
+}
+
+fn binomial(n: u128, k: u128) -> u128 {
+    (1..=k).fold(1, |acc, i| acc * (n - k + i) / i)
+}
+
+#[test]
+fn bidirectional_bfs_paths() {
+    let mut state = 11;
+    for n in [2, 10, 40, 120] {
+        let mut graph = SimpleGraph::empty(n);
+        for u in 1..n {
+            for v in 0..u {
+                if lcg(&mut state).is_multiple_of(n as u64 / 2 + 1) {
+                    graph.add_edge(u, v);
+                }
+            }
+        }
+        let view = graph.view();
+        for s in 0..n.min(8) {
+            let distances = view.all_pairs_shortest_paths().nth(s).unwrap().distances;
+            for (t, &distance) in distances.iter().enumerate() {
+                match view.bidirectional_bfs(&view, s, t) {
+                    Some(path) => {
+                        assert_eq!(path.len() - 1, distance);
+                        assert_eq!((path[0], *path.last().unwrap()), (s, t));
+                        assert!(path.windows(2).all(|e| graph.has_edge(e[0], e[1])));
+                        let bfs_path = PathingGraph::shortest_path(&view, s, t).unwrap();
+                        assert_eq!(bfs_path.len(), path.len());
+                    }
+                    None => {
+                        assert_eq!(distance, usize::MAX);
+                        assert!(PathingGraph::shortest_path(&view, s, t).is_err());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn count_shortest_paths() {
+    // Monotone lattice paths between opposite corners of a grid
+    let grid: SimpleGraph = squareknot::constructors::grid(7, 9);
+    let count = grid
+        .view()
+        .count_shortest_paths(&grid.view(), 0, 62)
+        .unwrap();
+    assert_eq!(count.distance, 14);
+    assert_eq!(count.count, binomial(14, 6));
+
+    // Antipodal vertices of a hypercube are joined by d! shortest paths
+    let cube: SimpleGraph = squareknot::constructors::hypercube(6);
+    let view = cube.view();
+    let count = view.count_shortest_paths(&view, 0, 63).unwrap();
+    assert_eq!((count.distance, count.count), (6, 720));
+
+    let trivial = view.count_shortest_paths(&view, 5, 5).unwrap();
+    assert_eq!((trivial.distance, trivial.count), (0, 1));
+
+    let mut disconnected = SimpleGraph::empty(3);
+    disconnected.add_edge(0, 1);
+    let view = disconnected.view();
+    assert!(view.count_shortest_paths(&view, 0, 2).is_none());
+    assert!(view.bidirectional_bfs(&view, 2, 0).is_none());
+}
+
+#[test]
+fn bidirectional_bfs_on_digraph() {
+    // Two routes of length 3 from 0 to 5, and a shortcut which only runs backwards
+    let mut graph = SimpleDiGraph::empty(6);
+    for (u, v) in [(0, 1), (1, 2), (2, 5), (0, 3), (3, 4), (4, 5), (5, 0)] {
+        graph.add_edge(u, v);
+    }
+    let view = graph.view();
+    let reverse = graph.transpose_view();
+
+    let path = view.bidirectional_bfs(&reverse, 0, 5).unwrap();
+    assert_eq!(path.len(), 4);
+    assert!(path.windows(2).all(|e| graph.has_edge(e[0], e[1])));
+    let count = view.count_shortest_paths(&reverse, 0, 5).unwrap();
+    assert_eq!((count.distance, count.count), (3, 2));
+    assert_eq!(view.bidirectional_bfs(&reverse, 5, 1), Some(vec![5, 0, 1]));
+}
+
+#[test]
+fn shortest_paths_follow_arcs() {
+    // 2 can reach 0 only through the reverse of the arc 2 -> 1
+    let mut graph = SimpleDiGraph::empty(5);
+    for (u, v) in [(0, 1), (0, 3), (0, 4), (2, 1)] {
+        graph.add_edge(u, v);
+    }
+    let view = graph.view();
+    let reverse = graph.transpose_view();
+    assert_eq!(PathingGraph::shortest_path(&view, 0, 2), Err(()));
+    assert_eq!(PathingGraph::shortest_path(&view, 2, 1), Ok(vec![2, 1]));
+    assert!(view.bidirectional_bfs(&reverse, 0, 2).is_none());
+    assert!(view.count_shortest_paths(&reverse, 0, 2).is_none());
+    assert_eq!(view.bidirectional_bfs(&reverse, 0, 4), Some(vec![0, 4]));
+}
@@ -0,0 +1,378 @@
+use rand::{rngs::StdRng, SeedableRng};
+use squareknot::{
+    constructors::random::gnp,
+    io::{dimacs::FakeDimacs, GraphFormat},
+    prelude::*,
+};
+
+fn sorted_neighbors<G: AbstractGraph<VertexLabel = usize>>(graph: &G, v: usize) -> Vec<usize> {
+    let mut neighbors = graph.neighbor_iterator(v).unwrap().collect::<Vec<_>>();
+    neighbors.sort();
+    neighbors
+}
+
+#[test]
+fn csr_builder() {
+    let mut builder = CsrBuilder::<u32>::new(5).symmetric();
+    builder
+        .add_edge(0, 3)
+        .add_edge(0, 1)
+        .add_edge(3, 0)
+        .add_edge(2, 2);
+    builder.extend([(4, 1), (1, 0)]);
+    let csr = builder.build();
+
+    assert_eq!(csr.num_v_labels(), 5);
+    assert_eq!(csr.num_entries(), 7);
+    assert_eq!(csr.neighbors(0), &[1, 3]);
+    assert_eq!(csr.neighbors(1), &[0, 4]);
+    assert_eq!(csr.neighbors(2), &[2]);
+    assert_eq!(csr.degree(3), 1);
+    assert!(csr.has_edge(4, 1) && !csr.has_edge(4, 0) && !csr.has_edge(4, 7));
+
+    let graph = CsrGraph::from_storage(csr);
+    assert_eq!((graph.order(), graph.size()), (5, 4));
+    assert_eq!(graph.view().component_count(), 2);
+
+    let arcs = CsrBuilder::<usize>::new(3);
+    assert_eq!(arcs.build(), Csr::empty(3));
+}
+
+#[test]
+fn csr_matches_adjacency_list() {
+    for (n, p, seed) in [(1, 0.5, 0), (60, 0.05, 1), (300, 0.02, 2), (200, 0.3, 3)] {
+        let graph: SimpleGraph = gnp(n, p, &mut StdRng::seed_from_u64(seed));
+        let csr = CsrGraph::<u32>::from_storage(CsrBuilder::from_storage(graph.storage()).build());
+
+        assert_eq!((csr.order(), csr.size()), (graph.order(), graph.size()));
+        for v in 0..n {
+            assert_eq!(
+                csr.neighbor_iterator(v).unwrap().collect::<Vec<_>>(),
+                sorted_neighbors(&graph, v)
+            );
+        }
+        assert_eq!(csr.view().component_count(), graph.view().component_count());
+        let depths = |nodes: Vec<TraversalNode>| {
+            let mut depths = nodes
+                .iter()
+                .map(|node| (node.vertex, node.depth))
+                .collect::<Vec<_>>();
+            depths.sort();
+            depths
+        };
+        assert_eq!(
+            depths(csr.view().bfs(0).collect()),
+            depths(graph.view().bfs(0).collect())
+        );
+        assert_eq!(
+            csr.view().articulation_points().len(),
+            graph.view().articulation_points().len()
+        );
+    }
+}
+
+#[test]
+fn csr_digraph() {
+    let mut graph = SimpleDiGraph::empty(6);
+    for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (5, 4)] {
+        graph.add_edge(u, v);
+    }
+    let out_storage: Csr = CsrBuilder::from_storage(graph.out_storage()).build();
+    let in_storage = out_storage.transpose();
+    let csr = CsrDiGraph::from_storage(out_storage, in_storage);
+
+    assert_eq!((csr.order(), csr.size()), (6, 7));
+    assert_eq!(
+        csr.in_neighbor_iterator(3).unwrap().collect::<Vec<_>>(),
+        vec![2, 4]
+    );
+    assert_eq!(csr.view().strongly_connected_components().count(), 3);
+    assert_eq!(csr.transpose_view().bfs(4).count(), 6);
+}
+
+#[test]
+fn csr_mutation() {
+    let mut csr = CsrGraph::<usize>::empty(3);
+    let mut list = SimpleGraph::empty(3);
+    let v = csr.add_vertex();
+    assert_eq!(v, list.add_vertex());
+    for (u, v) in [(0, 3), (2, 1), (0, 2), (3, 1), (0, 3)] {
+        assert_eq!(csr.add_edge(u, v), list.add_edge(u, v));
+    }
+    assert!(csr.rem_edge(2, 0) && list.rem_edge(2, 0));
+    assert!(!csr.rem_edge(2, 0));
+
+    assert_eq!(csr.size(), list.size());
+    for v in 0..4 {
+        assert_eq!(
+            csr.neighbor_iterator(v).unwrap().collect::<Vec<_>>(),
+            sorted_neighbors(&list, v)
+        );
+    }
+}
+
+#[test]
+fn bit_matrix_matches_adjacency_list() {
+    for (n, p, seed) in [
+        (1, 0.5, 0),
+        (63, 0.2, 1),
+        (64, 0.5, 2),
+        (130, 0.1, 3),
+        (200, 0.7, 4),
+    ] {
+        let graph: SimpleGraph = gnp(n, p, &mut StdRng::seed_from_u64(seed));
+        let mut dense = DenseGraph::empty(n);
+        for (u, v) in graph.edge_iterator() {
+            dense.add_edge(u, v);
+        }
+
+        assert_eq!(dense.size(), graph.size());
+        for v in 0..n {
+            assert_eq!(
+                dense.neighbor_iterator(v).unwrap().collect::<Vec<_>>(),
+                sorted_neighbors(&graph, v)
+            );
+            assert_eq!(
+                dense.storage().degree(v),
+                graph.neighbor_iterator(v).unwrap().count()
+            );
+        }
+        assert_eq!(
+            dense.view().component_count(),
+            graph.view().component_count()
+        );
+
+        let mut triangles = 0;
+        for u in 0..n {
+            for v in u + 1..n {
+                let common = sorted_neighbors(&graph, u)
+                    .into_iter()
+                    .filter(|&w| graph.has_edge(v, w))
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    dense.storage().common_neighbors(u, v).collect::<Vec<_>>(),
+                    common
+                );
+                assert_eq!(dense.storage().common_degree(u, v), common.len());
+                if graph.has_edge(u, v) {
+                    triangles += common.iter().filter(|&&w| w > v).count();
+                }
+            }
+        }
+        assert_eq!(dense.storage().triangle_count(), triangles);
+    }
+}
+
+#[test]
+fn bit_matrix_sets() {
+    let mut graph = DenseGraph::empty(3);
+    graph.add_edge(0, 1);
+    graph.add_edge(1, 2);
+    // Growing past a word boundary keeps existing rows intact
+    graph.add_vertices(100);
+    graph.add_edge(2, 90);
+    graph.add_edge(0, 70);
+    graph.add_edge(70, 90);
+    assert_eq!(graph.num_v_labels(), 103);
+    assert_eq!(
+        graph.neighbor_iterator(2).unwrap().collect::<Vec<_>>(),
+        vec![1, 90]
+    );
+
+    let storage = graph.storage();
+    assert_eq!(
+        storage.neighborhood_union(0, 2).collect::<Vec<_>>(),
+        vec![1, 70, 90]
+    );
+    assert_eq!(
+        storage.common_neighbors(0, 90).collect::<Vec<_>>(),
+        vec![70]
+    );
+    let mut set = storage.row(70).to_vec();
+    storage.union_into(1, &mut set);
+    assert_eq!(
+        BitIter::new(set.iter().copied()).collect::<Vec<_>>(),
+        vec![0, 2, 90]
+    );
+    storage.intersect_into(2, &mut set);
+    assert_eq!(BitIter::new(set).collect::<Vec<_>>(), vec![90]);
+
+    assert!(graph.rem_edge(90, 2));
+    assert!(!graph.has_edge(2, 90) && graph.size() == 4);
+
+    let mut digraph = DenseDiGraph::empty(70);
+    digraph.add_edge(69, 3);
+    assert_eq!(
+        digraph.in_neighbor_iterator(3).unwrap().collect::<Vec<_>>(),
+        vec![69]
+    );
+    assert!(digraph.neighbor_iterator(3).unwrap().next().is_none());
+}
+
+fn assert_same_graph<
+    G: AbstractGraph<VertexLabel = usize>,
+    H: AbstractGraph<VertexLabel = usize>,
+>(
+    a: &G,
+    b: &H,
+) {
+    assert_eq!(
+        (a.order(), a.size(), a.num_v_labels()),
+        (b.order(), b.size(), b.num_v_labels())
+    );
+    for v in 0..a.num_v_labels() {
+        assert_eq!(sorted_neighbors(a, v), sorted_neighbors(b, v));
+    }
+}
+
+#[test]
+fn storage_conversions() {
+    let graph: SimpleGraph = gnp(150, 0.05, &mut StdRng::seed_from_u64(7));
+
+    let csr = CsrGraph::<u32>::from(&graph);
+    let dense = DenseGraph::from(&csr);
+    let matrix: UnGraph<AdjacencyMatrix> = (&dense).into();
+    let back = SimpleGraph::from(&matrix);
+    assert_same_graph(&graph, &csr);
+    assert_same_graph(&graph, &dense);
+    assert_same_graph(&graph, &matrix);
+    assert_same_graph(&graph, &back);
+
+    let mut digraph = SimpleDiGraph::empty(5);
+    for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4)] {
+        digraph.add_edge(u, v);
+    }
+    let csr = CsrDiGraph::<usize>::from(&digraph);
+    assert_same_graph(&digraph, &csr);
+    assert_eq!(
+        csr.in_neighbor_iterator(0).unwrap().collect::<Vec<_>>(),
+        vec![2]
+    );
+    assert_eq!(csr.view().strongly_connected_components().count(), 3);
+}
+
+#[test]
+fn collect_views() {
+    let graph: SimpleGraph = gnp(80, 0.1, &mut StdRng::seed_from_u64(8));
+    assert_same_graph(&graph.view().collect_into::<SimpleGraph>(), &graph);
+
+    let filtered = graph.view().filter_vertices(|&v| v % 4 != 0);
+    let owned: CsrGraph = filtered.collect_into();
+    assert_eq!(owned.num_v_labels(), 80);
+    assert_eq!(
+        owned.size(),
+        graph
+            .edge_iterator()
+            .filter(|&(u, v)| u < v && u % 4 != 0 && v % 4 != 0)
+            .count()
+    );
+    assert!(owned.neighbor_iterator(8).unwrap().next().is_none());
+    assert_eq!(
+        owned.view().component_count(),
+        filtered.component_count() + 20
+    );
+
+    let compacted: SimpleGraph = graph
+        .view()
+        .filter_vertices(|&v| v % 4 != 0)
+        .compact()
+        .collect_into();
+    assert_eq!(
+        (compacted.num_v_labels(), compacted.size()),
+        (60, owned.size())
+    );
+
+    // An undirected view becomes a digraph with arcs both ways
+    let directed: SimpleDiGraph = graph
+        .view()
+        .filter_edges(|&(u, v)| u + v < 40)
+        .collect_into();
+    assert_eq!(
+        directed.size(),
+        2 * graph
+            .edge_iterator()
+            .filter(|&(u, v)| u < v && u + v < 40)
+            .count()
+    );
+}
+
+#[test]
+fn conversions_merge_repeated_edges() {
+    // The unchecked parser keeps the repeated edge, but converted storage holds it once
+    let graph: SimpleGraph = FakeDimacs::parse_graph("3 3\n0 1\n1 0\n1 2".as_bytes()).unwrap();
+    assert_eq!(graph.size(), 3);
+    let csr = CsrGraph::<usize>::from(&graph);
+    let dense = DenseGraph::from(&graph);
+    assert_eq!((csr.size(), dense.size()), (2, 2));
+    assert_eq!(csr.edge_iterator().count(), 4);
+
+    let digraph: SimpleDiGraph = FakeDimacs::parse_graph("3 3\n0 1\n0 1\n1 2".as_bytes()).unwrap();
+    assert_eq!(digraph.size(), 3);
+    let converted = DenseDiGraph::from(&digraph);
+    assert_eq!(converted.size(), 2);
+    assert_eq!(
+        converted
+            .in_neighbor_iterator(1)
+            .unwrap()
+            .collect::<Vec<_>>(),
+        vec![0]
+    );
+}
+
+#[test]
+fn weighted_conversions() {
+    let edges = [(0, 1, 4), (1, 2, 7), (0, 3, 2), (2, 3, 1)];
+    let mut list: SimpleWeightedGraph<u32> = SimpleWeightedGraph::empty(4);
+    let mut arcs: SimpleWeightedDiGraph<u32> = SimpleWeightedDiGraph::empty(4);
+    for (u, v, w) in edges {
+        list.add_weighted_edge(u, v, w);
+        arcs.add_weighted_edge(u, v, w);
+    }
+
+    let matrix = UnGraph::<WeightedAdjacencyMatrix<u32>>::from(&list);
+    let back = SimpleWeightedGraph::from(&matrix);
+    let arc_matrix = DiGraph::<WeightedAdjacencyMatrix<u32>>::from(&arcs);
+    let arcs_back = SimpleWeightedDiGraph::from(&arc_matrix);
+    assert_eq!((back.size(), arcs_back.size()), (4, 4));
+    for (u, v, w) in edges {
+        assert_eq!(matrix.edge_weight(v, u), Some(w));
+        assert_eq!(back.edge_weight(u, v), Some(w));
+        assert_eq!(back.edge_weight(v, u), Some(w));
+        assert_eq!(arc_matrix.edge_weight(u, v), Some(w));
+        assert_eq!(arcs_back.edge_weight(u, v), Some(w));
+        assert_eq!(arcs_back.edge_weight(v, u), None);
+    }
+    let mut incoming = arcs_back
+        .weighted_in_neighbor_iterator(3)
+        .unwrap()
+        .collect::<Vec<_>>();
+    incoming.sort();
+    assert_eq!(incoming, vec![(0, 2), (2, 1)]);
+
+    // Converting to unweighted storage keeps only the adjacency
+    let csr = CsrGraph::<usize>::from(&list);
+    assert_same_graph(&csr, &list);
+}
+
+#[test]
+fn adjacency_list_removal() {
+    // Neighbors are not stored in label order, so removal must search for the neighbor
+    let mut list = AdjacencyList::empty(3);
+    unsafe {
+        list.add_edge(0, 2);
+        list.add_edge(0, 1);
+    }
+    list.rem_edge(0, 2);
+    assert_eq!(list.neighbor_iterator(0).collect::<Vec<_>>(), vec![1]);
+    list.rem_edge(0, 2);
+    list.rem_edge(0, 1);
+    assert_eq!(list.neighbor_iterator(0).count(), 0);
+
+    let mut graph = SimpleGraph::empty(4);
+    for (u, v) in [(0, 3), (0, 1), (0, 2)] {
+        graph.add_edge(u, v);
+    }
+    assert!(graph.rem_edge(0, 3));
+    assert_eq!(sorted_neighbors(&graph, 0), vec![1, 2]);
+    assert_eq!(sorted_neighbors(&graph, 3), vec![]);
+}
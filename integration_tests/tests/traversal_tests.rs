@@ -39,3 +39,362 @@ fn dfs_comp_count() {
     println!("Component Count: {ncomp}");
     assert_eq!(ncomp, 5);
 }
+
+fn undirected(n: usize, edges: &[(usize, usize)]) -> SimpleGraph {
+    let mut graph = SimpleGraph::empty(n);
+    for &(u, v) in edges {
+        graph.add_edge(u, v);
+    }
+    graph
+}
+
+fn normalized(mut sets: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    for set in sets.iter_mut() {
+        set.sort();
+    }
+    sets.sort();
+    sets
+}
+
+#[test]
+fn articulation_points_and_bridges() {
+    // Two triangles sharing vertex 2, a pendant path 4 - 5 - 6 and an isolated vertex 7
+    let graph = undirected(
+        8,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 2),
+            (4, 5),
+            (5, 6),
+        ],
+    );
+    let result = graph.view().biconnectivity();
+
+    assert_eq!(result.articulation_points, vec![2, 4, 5]);
+    let mut bridges = result
+        .bridges
+        .iter()
+        .map(|&(u, v)| (u.min(v), u.max(v)))
+        .collect::<Vec<_>>();
+    bridges.sort();
+    assert_eq!(bridges, vec![(4, 5), (5, 6)]);
+
+    let blocks = result
+        .blocks
+        .iter()
+        .map(|block| {
+            let mut vertices = block.iter().flat_map(|&(u, v)| [u, v]).collect::<Vec<_>>();
+            vertices.sort();
+            vertices.dedup();
+            vertices
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        normalized(blocks),
+        vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5], vec![5, 6]]
+    );
+    assert_eq!(
+        result.blocks.iter().map(|b| b.len()).sum::<usize>(),
+        graph.size()
+    );
+
+    assert_eq!(
+        normalized(result.two_edge_connected_components),
+        vec![vec![0, 1, 2, 3, 4], vec![5], vec![6], vec![7]]
+    );
+}
+
+#[test]
+fn block_cut_tree() {
+    let graph = undirected(7, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]);
+    let tree = graph.view().block_cut_tree();
+
+    // Blocks {0, 1, 2}, {2, 3}, {3, 4, 5} and {6}, with cut vertices 2 and 3
+    assert_eq!(tree.blocks.len(), 4);
+    assert_eq!(tree.cut_vertices, vec![2, 3]);
+    assert_eq!(tree.num_v_labels(), 6);
+    assert_eq!(tree.edge_iterator().count(), 2 * 4);
+    assert!(!tree.is_block(tree.node_of[2]));
+    assert!(tree.is_block(tree.node_of[0]));
+    assert_eq!(tree.node_of[0], tree.node_of[1]);
+    assert_eq!(tree.neighbor_iterator(tree.node_of[3]).unwrap().count(), 2);
+
+    // A forest with one tree per component, here the triangle-bridge-triangle and the isolated vertex
+    assert_eq!(tree.component_count(), 2);
+}
+
+#[test]
+fn deep_biconnectivity() {
+    let n = 200_000;
+    let mut graph = SimpleGraph::empty(n);
+    for v in 1..n {
+        graph.add_edge(v - 1, v);
+    }
+    let result = graph.view().biconnectivity();
+    assert_eq!(result.bridges.len(), n - 1);
+    assert_eq!(result.articulation_points.len(), n - 2);
+
+    graph.add_edge(n - 1, 0);
+    let result = graph.view().biconnectivity();
+    assert!(result.bridges.is_empty() && result.articulation_points.is_empty());
+    assert_eq!(result.blocks.len(), 1);
+}
+/// Checks that each vertex forms a clique with its neighbors later in `order`.
+fn is_perfect_elimination_ordering(graph: &SimpleGraph, order: &[usize]) -> bool {
+    let mut position = vec![usize::MAX; graph.num_v_labels()];
+    for (i, &v) in order.iter().enumerate() {
+        position[v] = i;
+    }
+    order.iter().all(|&v| {
+        let later = graph
+            .neighbor_iterator(v)
+            .unwrap()
+            .filter(|&w| position[w] > position[v])
+            .collect::<Vec<_>>();
+        later
+            .iter()
+            .all(|&a| later.iter().all(|&b| a == b || graph.has_edge(a, b)))
+    })
+}
+
+fn assert_chordless_cycle(graph: &SimpleGraph, cycle: &[usize]) {
+    let k = cycle.len();
+    assert!(k >= 4);
+    for i in 0..k {
+        for j in i + 1..k {
+            let consecutive = j == i + 1 || (i == 0 && j == k - 1);
+            assert_eq!(graph.has_edge(cycle[i], cycle[j]), consecutive);
+        }
+    }
+}
+
+#[test]
+fn lex_bfs_and_mcs_orders() {
+    // Two triangles sharing the edge 1 - 2, with a pendant vertex 4 on vertex 3
+    let graph = undirected(5, &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    for order in [
+        graph.view().lex_bfs(),
+        graph.view().maximum_cardinality_search(),
+    ] {
+        assert_eq!(order.len(), 5);
+        assert_eq!(order[0], 0);
+        let reversed = order.iter().rev().copied().collect::<Vec<_>>();
+        assert!(is_perfect_elimination_ordering(&graph, &reversed));
+    }
+
+    // LexBFS visits the neighbors of the first vertex before any other vertex
+    let path = undirected(6, &[(0, 5), (5, 1), (1, 4), (4, 2), (0, 3)]);
+    let order = path.view().lex_bfs();
+    assert_eq!(&order[..3], &[0, 5, 3]);
+
+    // Filtered vertices are skipped
+    let filtered = graph.view().filter_vertices(|&v| v != 1);
+    assert_eq!(filtered.lex_bfs().len(), 4);
+    assert_eq!(filtered.maximum_cardinality_search().len(), 4);
+}
+
+#[test]
+fn chordal_recognition() {
+    let chordal = undirected(
+        7,
+        &[
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (3, 5),
+            (5, 6),
+        ],
+    );
+    let peo = chordal.view().perfect_elimination_ordering().unwrap();
+    assert!(is_perfect_elimination_ordering(&chordal, &peo));
+    assert!(chordal.view().is_chordal());
+
+    for n in 4..8 {
+        let mut cycle = SimpleGraph::empty(n);
+        for v in 0..n {
+            cycle.add_edge(v, (v + 1) % n);
+        }
+        let witness = cycle.view().perfect_elimination_ordering().unwrap_err();
+        assert_eq!(witness.cycle.len(), n);
+        assert_chordless_cycle(&cycle, &witness.cycle);
+    }
+
+    // A chordless 5-cycle with a triangle attached
+    let graph = undirected(
+        7,
+        &[
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+        ],
+    );
+    let witness = graph.view().perfect_elimination_ordering().unwrap_err();
+    assert_chordless_cycle(&graph, &witness.cycle);
+}
+
+#[test]
+fn chordal_cliques() {
+    let graph = undirected(
+        7,
+        &[
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (3, 5),
+            (5, 6),
+        ],
+    );
+    let cliques = normalized(graph.view().chordal_maximal_cliques().unwrap());
+    assert_eq!(
+        cliques,
+        vec![vec![0, 1, 2], vec![1, 2, 3], vec![3, 4, 5], vec![5, 6]]
+    );
+    assert_eq!(graph.view().chordal_chromatic_number(), Ok(3));
+
+    let tree = graph.view().clique_tree().unwrap();
+    assert_eq!(tree.cliques.len(), 4);
+    assert_eq!(tree.parents.iter().filter(|&&p| p == usize::MAX).count(), 1);
+    assert_eq!(tree.edge_iterator().count(), 2 * 3);
+    // The cliques containing each vertex form a subtree
+    for v in 0..7 {
+        let containing = (0..tree.cliques.len())
+            .filter(|&c| tree.cliques[c].contains(&v))
+            .collect::<Vec<_>>();
+        let subtree = tree.clone().filter_vertices(|c| containing.contains(c));
+        assert_eq!(subtree.component_count(), 1);
+    }
+
+    let complete = undirected(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    assert_eq!(complete.view().chordal_chromatic_number(), Ok(4));
+    let empty = SimpleGraph::empty(3);
+    assert_eq!(empty.view().chordal_maximal_cliques().unwrap().len(), 3);
+    assert_eq!(empty.view().clique_tree().unwrap().component_count(), 3);
+
+    let square = undirected(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    assert!(square.view().chordal_chromatic_number().is_err());
+}
+#[test]
+fn multi_source_regions() {
+    // A path 0 - 1 - ... - 9 with facilities at 0 and 7
+    let path = undirected(10, &(1..10).map(|v| (v - 1, v)).collect::<Vec<_>>());
+    let view = path.view();
+    let mut bfs = view.multi_source_bfs([0, 7, 7]);
+    let depths = bfs
+        .by_ref()
+        .map(|node| (node.vertex, node.depth))
+        .collect::<Vec<_>>();
+    assert_eq!(depths.len(), 10);
+    assert_eq!(&depths[..2], &[(0, 0), (7, 0)]);
+    assert!(depths.contains(&(3, 3)) && depths.contains(&(4, 3)) && depths.contains(&(9, 2)));
+
+    assert_eq!(bfs.sources(), &[0, 0, 0, 0, 7, 7, 7, 7, 7, 7]);
+    assert_eq!(bfs.source_of(3), Some(0));
+
+    // Recycled resources may be bounded, and need not come from a multi-source traversal
+    let resources = bfs.extract_resources();
+    let mut bfs = resources.multi_source_bfs(&view, [2, 8]).limit_depth(1);
+    assert_eq!(bfs.by_ref().count(), 6);
+    assert_eq!(bfs.source_of(0), None);
+
+    let resources = view.bfs(0).extract_resources();
+    let bfs = resources.multi_source_bfs(&view, [5]);
+    assert_eq!(bfs.count(), 10);
+}
+
+#[test]
+fn depth_limited_bfs() {
+    let graph = undirected(8, &[(0, 1), (0, 2), (1, 3), (2, 4), (3, 5), (4, 6), (6, 7)]);
+    let view = graph.view();
+
+    let mut within_two = view
+        .depth_limited_bfs(0, 2)
+        .map(|node| node.vertex)
+        .collect::<Vec<_>>();
+    within_two.sort();
+    assert_eq!(within_two, vec![0, 1, 2, 3, 4]);
+    assert_eq!(view.depth_limited_bfs(0, 0).count(), 1);
+
+    // The limit survives restarts and recycling
+    let mut bfs = view.depth_limited_bfs(6, 1);
+    assert_eq!(bfs.by_ref().count(), 3);
+    bfs.restart_at(7);
+    assert_eq!(bfs.by_ref().count(), 2);
+    let bfs = bfs.extract_resources().bfs(&view, 3).limit_depth(3);
+    assert!(bfs.map(|node| node.depth).all(|depth| depth <= 3));
+}
+fn check_parallel_bfs<G: TraversalView>(
+    view: &G,
+    result: &squareknot::traversal::ParallelBFS,
+    root: usize,
+) {
+    let mut depths = vec![usize::MAX; view.num_v_labels()];
+    for node in view.bfs(root) {
+        depths[node.vertex] = node.depth;
+    }
+    assert_eq!(result.depths, depths);
+    for v in result.traversed_iter().filter(|&v| v != root) {
+        let parent = result.parents[v];
+        assert_eq!(result.depths[parent] + 1, result.depths[v]);
+        assert!(view.neighbor_iterator(parent).unwrap().any(|w| w == v));
+    }
+    assert_eq!(result.parents[root], root);
+}
+
+#[test]
+fn parallel_bfs_matches_sequential() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use squareknot::constructors::random::gnp;
+
+    // Sparse graphs stay top-down, while dense ones switch to bottom-up expansion
+    for (n, p, seed) in [
+        (1, 0.5, 0),
+        (500, 0.002, 1),
+        (2000, 0.003, 2),
+        (800, 0.05, 3),
+        (300, 0.6, 4),
+    ] {
+        let graph: SimpleGraph = gnp(n, p, &mut StdRng::seed_from_u64(seed));
+        let view = graph.view();
+        check_parallel_bfs(&view, &view.parallel_bfs(0), 0);
+
+        let filtered = graph.view().filter_vertices(|v| v % 3 != 1);
+        check_parallel_bfs(&filtered, &filtered.parallel_bfs(0), 0);
+    }
+
+    let mut state = 3u64;
+    let mut digraph = SimpleDiGraph::empty(400);
+    for _ in 0..4000 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let (u, v) = ((state >> 33) as usize % 400, (state >> 13) as usize % 400);
+        if u != v {
+            digraph.add_edge(u, v);
+        }
+    }
+    let view = digraph.view();
+    for root in [0, 17, 399] {
+        check_parallel_bfs(
+            &view,
+            &view.parallel_bfs_with(&digraph.transpose_view(), root),
+            root,
+        );
+    }
+}
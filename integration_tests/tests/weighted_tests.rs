@@ -0,0 +1,124 @@
+use std::ops::Add;
+
+use squareknot::prelude::*;
+
+#[test]
+fn weighted_list_and_matrix_agree() {
+    let edges = [(0, 1, 4), (1, 2, 7), (0, 3, 2), (2, 3, 1)];
+
+    let mut list: SimpleWeightedGraph<u32> = SimpleWeightedGraph::empty(4);
+    let mut matrix: UnGraph<WeightedAdjacencyMatrix<u32>> = UnGraph::empty(4);
+    for (u, v, w) in edges {
+        assert!(list.add_weighted_edge(u, v, w));
+        assert!(matrix.add_weighted_edge(u, v, w));
+    }
+    assert!(!list.add_weighted_edge(1, 0, 9));
+
+    assert_eq!(list.size(), 4);
+    for (u, v, w) in edges {
+        assert_eq!(list.edge_weight(u, v), Some(w));
+        assert_eq!(list.edge_weight(v, u), Some(w));
+        assert_eq!(matrix.edge_weight(u, v), Some(w));
+    }
+    assert_eq!(list.edge_weight(1, 3), None);
+
+    let mut a = list
+        .weighted_neighbor_iterator(0)
+        .unwrap()
+        .collect::<Vec<_>>();
+    let mut b = matrix
+        .weighted_neighbor_iterator(0)
+        .unwrap()
+        .collect::<Vec<_>>();
+    a.sort();
+    b.sort();
+    assert_eq!(a, vec![(1, 4), (3, 2)]);
+    assert_eq!(a, b);
+
+    // Unweighted edges receive unit weight
+    list.add_edge(1, 3);
+    assert_eq!(list.edge_weight(3, 1), Some(1));
+}
+
+#[test]
+fn weighted_digraph() {
+    let mut graph: SimpleWeightedDiGraph<f64> = SimpleWeightedDiGraph::empty(3);
+    graph.add_weighted_edge(0, 1, 0.5);
+    graph.add_weighted_edge(2, 1, 1.5);
+
+    assert_eq!(graph.edge_weight(1, 0), None);
+    let mut inc = graph
+        .weighted_in_neighbor_iterator(1)
+        .unwrap()
+        .collect::<Vec<_>>();
+    inc.sort_by_key(|&(v, _)| v);
+    assert_eq!(inc, vec![(0, 0.5), (2, 1.5)]);
+
+    let transpose = graph.transpose_view();
+    let out = transpose
+        .weighted_neighbor_iterator(1)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(out.len(), 2);
+}
+
+#[test]
+fn combinators_keep_weights() {
+    let mut graph: SimpleWeightedGraph<i64> = SimpleWeightedGraph::empty(5);
+    for u in 1..5 {
+        graph.add_weighted_edge(u - 1, u, u as i64 * 10);
+    }
+
+    let view = graph
+        .view()
+        .filter_vertices(|&v| v != 4)
+        .filter_edges(|&(u, v)| u + v != 3);
+
+    let mut edges = view
+        .weighted_edge_iterator()
+        .filter(|&(u, v, _)| u < v)
+        .collect::<Vec<_>>();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 1, 10), (2, 3, 30)]);
+
+    let neigh = view
+        .weighted_neighbor_iterator(3)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(neigh, vec![(2, 30)]);
+    assert!(view.weighted_neighbor_iterator(4).is_none());
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Cost {
+    hops: u32,
+    toll: u32,
+}
+
+impl Add for Cost {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Cost {
+            hops: self.hops + rhs.hops,
+            toll: self.toll + rhs.toll,
+        }
+    }
+}
+
+impl Weight for Cost {
+    fn zero() -> Self {
+        Cost { hops: 0, toll: 0 }
+    }
+
+    fn one() -> Self {
+        Cost { hops: 1, toll: 0 }
+    }
+}
+
+#[test]
+fn custom_weight() {
+    let mut graph: SimpleWeightedGraph<Cost> = SimpleWeightedGraph::empty(2);
+    graph.add_edge(0, 1);
+    assert_eq!(graph.edge_weight(0, 1), Some(Cost { hops: 1, toll: 0 }));
+}